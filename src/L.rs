@@ -1,3 +1,5 @@
+#![allow(static_mut_refs)]
+
 use std::io::{self, Write};
 
 fn solve() {
@@ -1,37 +1,31 @@
 pub mod fenwick {
+    use crate::math::algebra::algebra::AddGroup;
+
     #[derive(Default, Debug)]
     pub struct Fenwick<T> {
         sum: Vec<T>,
         n: usize,
     }
 
-    impl<T> Fenwick<T>
-    where
-        T: Default
-            + Clone
-            + Copy
-            + std::ops::AddAssign
-            + std::ops::Sub<Output = T>
-            + std::ops::Neg<Output = T>,
-    {
+    impl<T: AddGroup + Copy> Fenwick<T> {
         pub fn new(n: usize) -> Self {
             Self {
-                sum: vec![T::default(); n + 1],
+                sum: vec![T::zero(); n + 1],
                 n,
             }
         }
 
         pub fn add(&mut self, mut x: usize, v: T) {
             while x <= self.n {
-                self.sum[x] += v;
+                self.sum[x] = self.sum[x] + v;
                 x += x & x.wrapping_neg();
             }
         }
 
         pub fn get(&self, mut x: usize) -> T {
-            let mut ans = T::default();
+            let mut ans = T::zero();
             while x > 0 {
-                ans += self.sum[x];
+                ans = ans + self.sum[x];
                 x -= x & x.wrapping_neg();
             }
             ans
@@ -71,5 +65,13 @@ mod tests {
 
         assert_eq!(fen.get_range(1, 10), 55.into());
         assert_eq!(fen.get_range(3, 8), 33.into());
+
+        use crate::math::ratio::ratio::Ratio;
+        let mut fen = Fenwick::<Ratio>::new(10);
+        for i in 1..=10 {
+            fen.add(i, Ratio::new(1, i as i64));
+        }
+        assert_eq!(fen.get_range(1, 3), Ratio::new(11, 6));
+        assert_eq!(fen.get_range(4, 4), Ratio::new(1, 4));
     }
 }
@@ -0,0 +1,431 @@
+pub mod lazy_segment_tree {
+    use crate::math::algebra::algebra::{Monoid, Semiring, Zero};
+
+    pub trait MapMonoid {
+        type M: Monoid;
+        type F: Clone;
+        fn identity_map() -> Self::F;
+        fn mapping(f: &Self::F, x: &<Self::M as Monoid>::S) -> <Self::M as Monoid>::S;
+        // f after g
+        fn composition(f: &Self::F, g: &Self::F) -> Self::F;
+    }
+
+    pub struct SumWithLen<T>(std::marker::PhantomData<T>);
+    impl<T: Zero + Copy> Monoid for SumWithLen<T> {
+        type S = (T, usize);
+        fn identity() -> (T, usize) {
+            (T::zero(), 0)
+        }
+        fn op(a: &(T, usize), b: &(T, usize)) -> (T, usize) {
+            (a.0 + b.0, a.1 + b.1)
+        }
+    }
+
+    pub struct RangeAddRangeSum<T>(std::marker::PhantomData<T>);
+    impl<T> MapMonoid for RangeAddRangeSum<T>
+    where
+        T: Semiring + From<i64>,
+    {
+        type M = SumWithLen<T>;
+        type F = T;
+        fn identity_map() -> T {
+            T::zero()
+        }
+        fn mapping(f: &T, x: &(T, usize)) -> (T, usize) {
+            (x.0 + *f * T::from(x.1 as i64), x.1)
+        }
+        fn composition(f: &T, g: &T) -> T {
+            *f + *g
+        }
+    }
+
+    pub struct RangeAssignRangeSum<T>(std::marker::PhantomData<T>);
+    impl<T> MapMonoid for RangeAssignRangeSum<T>
+    where
+        T: Semiring + From<i64>,
+    {
+        type M = SumWithLen<T>;
+        type F = Option<T>;
+        fn identity_map() -> Option<T> {
+            None
+        }
+        fn mapping(f: &Option<T>, x: &(T, usize)) -> (T, usize) {
+            match f {
+                Some(v) => (*v * T::from(x.1 as i64), x.1),
+                None => *x,
+            }
+        }
+        fn composition(f: &Option<T>, g: &Option<T>) -> Option<T> {
+            f.or(*g)
+        }
+    }
+
+    // (a, b) maps x to a * x + b
+    pub struct RangeAffineRangeSum<T>(std::marker::PhantomData<T>);
+    impl<T> MapMonoid for RangeAffineRangeSum<T>
+    where
+        T: Semiring + From<i64>,
+    {
+        type M = SumWithLen<T>;
+        type F = (T, T);
+        fn identity_map() -> (T, T) {
+            (T::one(), T::zero())
+        }
+        fn mapping(f: &(T, T), x: &(T, usize)) -> (T, usize) {
+            (f.0 * x.0 + f.1 * T::from(x.1 as i64), x.1)
+        }
+        fn composition(f: &(T, T), g: &(T, T)) -> (T, T) {
+            (f.0 * g.0, f.0 * g.1 + f.1)
+        }
+    }
+
+    type S<F> = <<F as MapMonoid>::M as Monoid>::S;
+
+    pub struct LazySegmentTree<F: MapMonoid> {
+        n: usize,
+        size: usize,
+        log: usize,
+        d: Vec<S<F>>,
+        lz: Vec<F::F>,
+    }
+
+    impl<F: MapMonoid> LazySegmentTree<F> {
+        pub fn new(n: usize) -> Self {
+            Self::from_slice(&vec![F::M::identity(); n])
+        }
+
+        pub fn from_slice(v: &[S<F>]) -> Self {
+            let n = v.len();
+            let size = n.next_power_of_two();
+            let log = size.trailing_zeros() as usize;
+            let mut d = vec![F::M::identity(); 2 * size];
+            d[size..size + n].clone_from_slice(v);
+            let lz = vec![F::identity_map(); size];
+            let mut tree = Self {
+                n,
+                size,
+                log,
+                d,
+                lz,
+            };
+            for i in (1..size).rev() {
+                tree.update(i);
+            }
+            tree
+        }
+
+        fn update(&mut self, k: usize) {
+            self.d[k] = F::M::op(&self.d[2 * k], &self.d[2 * k + 1]);
+        }
+
+        fn all_apply(&mut self, k: usize, f: &F::F) {
+            self.d[k] = F::mapping(f, &self.d[k]);
+            if k < self.size {
+                self.lz[k] = F::composition(f, &self.lz[k]);
+            }
+        }
+
+        fn push(&mut self, k: usize) {
+            let f = std::mem::replace(&mut self.lz[k], F::identity_map());
+            self.all_apply(2 * k, &f);
+            self.all_apply(2 * k + 1, &f);
+        }
+
+        pub fn len(&self) -> usize {
+            self.n
+        }
+
+        pub fn is_empty(&self) -> bool {
+            self.n == 0
+        }
+
+        pub fn set(&mut self, p: usize, x: S<F>) {
+            assert!(p < self.n);
+            let p = p + self.size;
+            for i in (1..=self.log).rev() {
+                self.push(p >> i);
+            }
+            self.d[p] = x;
+            for i in 1..=self.log {
+                self.update(p >> i);
+            }
+        }
+
+        pub fn get(&mut self, p: usize) -> S<F> {
+            assert!(p < self.n);
+            let p = p + self.size;
+            for i in (1..=self.log).rev() {
+                self.push(p >> i);
+            }
+            self.d[p].clone()
+        }
+
+        // fold of [l, r)
+        pub fn prod(&mut self, l: usize, r: usize) -> S<F> {
+            assert!(l <= r && r <= self.n);
+            if l == r {
+                return F::M::identity();
+            }
+            let mut l = l + self.size;
+            let mut r = r + self.size;
+            for i in (1..=self.log).rev() {
+                if ((l >> i) << i) != l {
+                    self.push(l >> i);
+                }
+                if ((r >> i) << i) != r {
+                    self.push((r - 1) >> i);
+                }
+            }
+            let mut sml = F::M::identity();
+            let mut smr = F::M::identity();
+            while l < r {
+                if l & 1 == 1 {
+                    sml = F::M::op(&sml, &self.d[l]);
+                    l += 1;
+                }
+                if r & 1 == 1 {
+                    r -= 1;
+                    smr = F::M::op(&self.d[r], &smr);
+                }
+                l >>= 1;
+                r >>= 1;
+            }
+            F::M::op(&sml, &smr)
+        }
+
+        pub fn all_prod(&self) -> S<F> {
+            self.d[1].clone()
+        }
+
+        pub fn apply(&mut self, p: usize, f: F::F) {
+            assert!(p < self.n);
+            let p = p + self.size;
+            for i in (1..=self.log).rev() {
+                self.push(p >> i);
+            }
+            self.d[p] = F::mapping(&f, &self.d[p]);
+            for i in 1..=self.log {
+                self.update(p >> i);
+            }
+        }
+
+        // apply f to every element of [l, r)
+        pub fn apply_range(&mut self, l: usize, r: usize, f: F::F) {
+            assert!(l <= r && r <= self.n);
+            if l == r {
+                return;
+            }
+            let l = l + self.size;
+            let r = r + self.size;
+            for i in (1..=self.log).rev() {
+                if ((l >> i) << i) != l {
+                    self.push(l >> i);
+                }
+                if ((r >> i) << i) != r {
+                    self.push((r - 1) >> i);
+                }
+            }
+            {
+                let mut l = l;
+                let mut r = r;
+                while l < r {
+                    if l & 1 == 1 {
+                        self.all_apply(l, &f);
+                        l += 1;
+                    }
+                    if r & 1 == 1 {
+                        r -= 1;
+                        self.all_apply(r, &f);
+                    }
+                    l >>= 1;
+                    r >>= 1;
+                }
+            }
+            for i in 1..=self.log {
+                if ((l >> i) << i) != l {
+                    self.update(l >> i);
+                }
+                if ((r >> i) << i) != r {
+                    self.update((r - 1) >> i);
+                }
+            }
+        }
+
+        // largest r such that g(prod(l, r)) holds, g must be monotone and g(identity) true
+        pub fn max_right<G>(&mut self, l: usize, g: G) -> usize
+        where
+            G: Fn(&S<F>) -> bool,
+        {
+            assert!(l <= self.n);
+            assert!(g(&F::M::identity()));
+            if l == self.n {
+                return self.n;
+            }
+            let mut l = l + self.size;
+            for i in (1..=self.log).rev() {
+                self.push(l >> i);
+            }
+            let mut sm = F::M::identity();
+            loop {
+                while l & 1 == 0 {
+                    l >>= 1;
+                }
+                if !g(&F::M::op(&sm, &self.d[l])) {
+                    while l < self.size {
+                        self.push(l);
+                        l *= 2;
+                        let res = F::M::op(&sm, &self.d[l]);
+                        if g(&res) {
+                            sm = res;
+                            l += 1;
+                        }
+                    }
+                    return l - self.size;
+                }
+                sm = F::M::op(&sm, &self.d[l]);
+                l += 1;
+                if l & l.wrapping_neg() == l {
+                    break;
+                }
+            }
+            self.n
+        }
+
+        // smallest l such that g(prod(l, r)) holds, g must be monotone and g(identity) true
+        pub fn min_left<G>(&mut self, r: usize, g: G) -> usize
+        where
+            G: Fn(&S<F>) -> bool,
+        {
+            assert!(r <= self.n);
+            assert!(g(&F::M::identity()));
+            if r == 0 {
+                return 0;
+            }
+            let mut r = r + self.size;
+            for i in (1..=self.log).rev() {
+                self.push((r - 1) >> i);
+            }
+            let mut sm = F::M::identity();
+            loop {
+                r -= 1;
+                while r > 1 && r & 1 == 1 {
+                    r >>= 1;
+                }
+                if !g(&F::M::op(&self.d[r], &sm)) {
+                    while r < self.size {
+                        self.push(r);
+                        r = 2 * r + 1;
+                        let res = F::M::op(&self.d[r], &sm);
+                        if g(&res) {
+                            sm = res;
+                            r -= 1;
+                        }
+                    }
+                    return r + 1 - self.size;
+                }
+                sm = F::M::op(&self.d[r], &sm);
+                if r & r.wrapping_neg() == r {
+                    break;
+                }
+            }
+            0
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::lazy_segment_tree::*;
+    use crate::math::modint::modint::mint998244353;
+    use crate::test_util::test_util::XorShift;
+
+    #[test]
+    fn test_lazy_segment_tree_affine() {
+        let mut rng = XorShift::default();
+        for n in 1..=20 {
+            let mut a: Vec<mint998244353> = (0..n)
+                .map(|_| mint998244353::new(rng.next_u64() as i64))
+                .collect();
+            let init: Vec<_> = a.iter().map(|&x| (x, 1)).collect();
+            let mut seg = LazySegmentTree::<RangeAffineRangeSum<mint998244353>>::from_slice(&init);
+            for _ in 0..100 {
+                let l = rng.below((n + 1) as u64) as usize;
+                let r = l + rng.below((n + 1 - l) as u64) as usize;
+                match rng.below(3) {
+                    0 => {
+                        let b = mint998244353::new(rng.next_u64() as i64);
+                        let c = mint998244353::new(rng.next_u64() as i64);
+                        seg.apply_range(l, r, (b, c));
+                        for x in a[l..r].iter_mut() {
+                            *x = b * *x + c;
+                        }
+                    }
+                    1 => {
+                        let sum = a[l..r].iter().fold(0.into(), |s, &x| s + x);
+                        assert_eq!(seg.prod(l, r), (sum, r - l));
+                    }
+                    _ => {
+                        let p = rng.below(n as u64) as usize;
+                        let x = mint998244353::new(rng.next_u64() as i64);
+                        seg.set(p, (x, 1));
+                        a[p] = x;
+                        assert_eq!(seg.get(p), (x, 1));
+                    }
+                }
+            }
+            let sum = a.iter().fold(0.into(), |s, &x| s + x);
+            assert_eq!(seg.all_prod(), (sum, n));
+        }
+    }
+
+    #[test]
+    fn test_lazy_segment_tree_add_assign() {
+        let mut seg = LazySegmentTree::<RangeAddRangeSum<i64>>::from_slice(&[(0, 1); 10]);
+        seg.apply_range(2, 7, 3);
+        seg.apply_range(0, 4, -1);
+        assert_eq!(seg.prod(0, 10).0, 11);
+        assert_eq!(seg.prod(3, 5).0, 5);
+        assert_eq!(seg.get(1).0, -1);
+
+        let mut seg = LazySegmentTree::<RangeAssignRangeSum<i64>>::from_slice(&[(1, 1); 10]);
+        seg.apply_range(2, 7, Some(5));
+        seg.apply_range(5, 9, Some(2));
+        assert_eq!(seg.prod(0, 10).0, 1 + 1 + 5 * 3 + 2 * 4 + 1);
+        assert_eq!(seg.get(6).0, 2);
+    }
+
+    #[test]
+    fn test_lazy_segment_tree_binary_search() {
+        let mut rng = XorShift::default();
+        for n in 0..=20 {
+            let mut a: Vec<i64> = (0..n).map(|_| rng.range(0, 9)).collect();
+            let init: Vec<_> = a.iter().map(|&x| (x, 1)).collect();
+            let mut seg = LazySegmentTree::<RangeAddRangeSum<i64>>::from_slice(&init);
+            for _ in 0..20 {
+                let l = rng.below((n + 1) as u64) as usize;
+                let r = l + rng.below((n + 1 - l) as u64) as usize;
+                let v = rng.range(0, 4);
+                seg.apply_range(l, r, v);
+                for x in a[l..r].iter_mut() {
+                    *x += v;
+                }
+                let bound = rng.range(0, 59);
+                let f = |x: &(i64, usize)| x.0 <= bound;
+                for l in 0..=n {
+                    let mut expected = l;
+                    while expected < n && a[l..=expected].iter().sum::<i64>() <= bound {
+                        expected += 1;
+                    }
+                    assert_eq!(seg.max_right(l, f), expected);
+                }
+                for r in 0..=n {
+                    let mut expected = r;
+                    while expected > 0 && a[expected - 1..r].iter().sum::<i64>() <= bound {
+                        expected -= 1;
+                    }
+                    assert_eq!(seg.min_left(r, f), expected);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,222 @@
+pub mod segment_tree {
+    pub use crate::math::algebra::algebra::{Additive, Monoid};
+
+    pub struct SegmentTree<M: Monoid> {
+        n: usize,
+        size: usize,
+        log: usize,
+        d: Vec<M::S>,
+    }
+
+    impl<M: Monoid> SegmentTree<M> {
+        pub fn new(n: usize) -> Self {
+            Self::from_slice(&vec![M::identity(); n])
+        }
+
+        pub fn from_slice(v: &[M::S]) -> Self {
+            let n = v.len();
+            let size = n.next_power_of_two();
+            let log = size.trailing_zeros() as usize;
+            let mut d = vec![M::identity(); 2 * size];
+            d[size..size + n].clone_from_slice(v);
+            let mut tree = Self { n, size, log, d };
+            for i in (1..size).rev() {
+                tree.update(i);
+            }
+            tree
+        }
+
+        fn update(&mut self, k: usize) {
+            self.d[k] = M::op(&self.d[2 * k], &self.d[2 * k + 1]);
+        }
+
+        pub fn len(&self) -> usize {
+            self.n
+        }
+
+        pub fn is_empty(&self) -> bool {
+            self.n == 0
+        }
+
+        pub fn set(&mut self, p: usize, x: M::S) {
+            assert!(p < self.n);
+            let p = p + self.size;
+            self.d[p] = x;
+            for i in 1..=self.log {
+                self.update(p >> i);
+            }
+        }
+
+        pub fn get(&self, p: usize) -> M::S {
+            assert!(p < self.n);
+            self.d[p + self.size].clone()
+        }
+
+        // fold of [l, r)
+        pub fn prod(&self, l: usize, r: usize) -> M::S {
+            assert!(l <= r && r <= self.n);
+            let mut sml = M::identity();
+            let mut smr = M::identity();
+            let mut l = l + self.size;
+            let mut r = r + self.size;
+            while l < r {
+                if l & 1 == 1 {
+                    sml = M::op(&sml, &self.d[l]);
+                    l += 1;
+                }
+                if r & 1 == 1 {
+                    r -= 1;
+                    smr = M::op(&self.d[r], &smr);
+                }
+                l >>= 1;
+                r >>= 1;
+            }
+            M::op(&sml, &smr)
+        }
+
+        pub fn all_prod(&self) -> M::S {
+            self.d[1].clone()
+        }
+
+        // largest r such that f(prod(l, r)) holds, f must be monotone and f(identity) true
+        pub fn max_right<F>(&self, l: usize, f: F) -> usize
+        where
+            F: Fn(&M::S) -> bool,
+        {
+            assert!(l <= self.n);
+            assert!(f(&M::identity()));
+            if l == self.n {
+                return self.n;
+            }
+            let mut l = l + self.size;
+            let mut sm = M::identity();
+            loop {
+                while l & 1 == 0 {
+                    l >>= 1;
+                }
+                if !f(&M::op(&sm, &self.d[l])) {
+                    while l < self.size {
+                        l *= 2;
+                        let res = M::op(&sm, &self.d[l]);
+                        if f(&res) {
+                            sm = res;
+                            l += 1;
+                        }
+                    }
+                    return l - self.size;
+                }
+                sm = M::op(&sm, &self.d[l]);
+                l += 1;
+                if l & l.wrapping_neg() == l {
+                    break;
+                }
+            }
+            self.n
+        }
+
+        // smallest l such that f(prod(l, r)) holds, f must be monotone and f(identity) true
+        pub fn min_left<F>(&self, r: usize, f: F) -> usize
+        where
+            F: Fn(&M::S) -> bool,
+        {
+            assert!(r <= self.n);
+            assert!(f(&M::identity()));
+            if r == 0 {
+                return 0;
+            }
+            let mut r = r + self.size;
+            let mut sm = M::identity();
+            loop {
+                r -= 1;
+                while r > 1 && r & 1 == 1 {
+                    r >>= 1;
+                }
+                if !f(&M::op(&self.d[r], &sm)) {
+                    while r < self.size {
+                        r = 2 * r + 1;
+                        let res = M::op(&self.d[r], &sm);
+                        if f(&res) {
+                            sm = res;
+                            r -= 1;
+                        }
+                    }
+                    return r + 1 - self.size;
+                }
+                sm = M::op(&self.d[r], &sm);
+                if r & r.wrapping_neg() == r {
+                    break;
+                }
+            }
+            0
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::segment_tree::*;
+    use crate::test_util::test_util::XorShift;
+
+    struct Max;
+    impl Monoid for Max {
+        type S = i64;
+        fn identity() -> i64 {
+            i64::MIN
+        }
+        fn op(a: &i64, b: &i64) -> i64 {
+            *a.max(b)
+        }
+    }
+
+    #[test]
+    fn test_segment_tree() {
+        let mut rng = XorShift::default();
+        for n in 0..=20 {
+            let mut a: Vec<i64> = (0..n).map(|_| rng.range(-50, 49)).collect();
+            let mut seg = SegmentTree::<Additive<i64>>::from_slice(&a);
+            for _ in 0..50 {
+                if n > 0 {
+                    let p = rng.below(n as u64) as usize;
+                    let x = rng.range(-50, 49);
+                    a[p] = x;
+                    seg.set(p, x);
+                    assert_eq!(seg.get(p), x);
+                }
+                for l in 0..=n {
+                    for r in l..=n {
+                        assert_eq!(seg.prod(l, r), a[l..r].iter().sum::<i64>());
+                    }
+                }
+                assert_eq!(seg.all_prod(), a.iter().sum::<i64>());
+            }
+        }
+
+        use crate::math::modint::modint::mint998244353;
+        let mut seg = SegmentTree::<Additive<mint998244353>>::new(10);
+        for i in 0..10 {
+            seg.set(i, (i as i64 + 1).into());
+        }
+        assert_eq!(seg.prod(0, 10), 55.into());
+        assert_eq!(seg.prod(2, 8), 33.into());
+    }
+
+    #[test]
+    fn test_segment_tree_binary_search() {
+        let mut rng = XorShift::default();
+        for n in 0..=20 {
+            let a: Vec<i64> = (0..n).map(|_| rng.range(0, 99)).collect();
+            let seg = SegmentTree::<Max>::from_slice(&a);
+            for bound in [0, 25, 50, 75, 100] {
+                let f = |x: &i64| *x < bound;
+                for l in 0..=n {
+                    let expected = (l..n).find(|&i| a[i] >= bound).unwrap_or(n);
+                    assert_eq!(seg.max_right(l, f), expected);
+                }
+                for r in 0..=n {
+                    let expected = (0..r).rev().find(|&i| a[i] >= bound).map_or(0, |i| i + 1);
+                    assert_eq!(seg.min_left(r, f), expected);
+                }
+            }
+        }
+    }
+}
@@ -1,7 +1,7 @@
 pub mod sssp {
     use std::collections::BinaryHeap;
 
-    pub fn dijkstra_sssp(graph: &Vec<Vec<(usize, i64)>>, source: usize) -> Vec<i64> {
+    pub fn dijkstra_sssp(graph: &[Vec<(usize, i64)>], source: usize) -> Vec<i64> {
         let n = graph.len() - 1;
         let mut dis = vec![i64::MAX; n + 1];
         let mut que = BinaryHeap::new();
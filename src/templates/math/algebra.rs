@@ -0,0 +1,215 @@
+pub mod algebra {
+    use std::marker::PhantomData;
+    use std::ops::{Add, Div, Mul, Neg, Sub};
+
+    pub trait Zero: Sized + Add<Output = Self> {
+        fn zero() -> Self;
+        fn is_zero(&self) -> bool;
+    }
+
+    pub trait One: Sized + Mul<Output = Self> {
+        fn one() -> Self;
+    }
+
+    // values forming a group under +
+    pub trait AddGroup: Zero + Sub<Output = Self> + Neg<Output = Self> {}
+    impl<T: Zero + Sub<Output = T> + Neg<Output = T>> AddGroup for T {}
+
+    // + and * with their identities, + need not be invertible
+    pub trait Semiring: Copy + Zero + One {}
+    impl<T: Copy + Zero + One> Semiring for T {}
+
+    pub trait Ring: Semiring + AddGroup {}
+    impl<T: Semiring + AddGroup> Ring for T {}
+
+    pub trait Field: Ring + Div<Output = Self> {
+        // treated as zero by elimination, overridden by inexact types
+        fn is_negligible(&self) -> bool {
+            self.is_zero()
+        }
+        // the row with the largest score becomes the pivot
+        fn pivot_score(&self) -> f64 {
+            if self.is_negligible() {
+                0.0
+            } else {
+                1.0
+            }
+        }
+    }
+
+    // a monoid on S given by its operation, for structures combining values in a fixed order
+    pub trait Monoid {
+        type S: Clone;
+        fn identity() -> Self::S;
+        fn op(a: &Self::S, b: &Self::S) -> Self::S;
+    }
+
+    // op(a, inv(a)) = op(inv(a), a) = identity, op need not commute
+    pub trait Group: Monoid {
+        fn inv(a: &Self::S) -> Self::S;
+    }
+
+    pub struct Additive<T>(PhantomData<T>);
+    impl<T: Zero + Clone> Monoid for Additive<T> {
+        type S = T;
+        fn identity() -> T {
+            T::zero()
+        }
+        fn op(a: &T, b: &T) -> T {
+            a.clone() + b.clone()
+        }
+    }
+    impl<T: AddGroup + Clone> Group for Additive<T> {
+        fn inv(a: &T) -> T {
+            -a.clone()
+        }
+    }
+
+    pub struct Multiplicative<T>(PhantomData<T>);
+    impl<T: One + Clone> Monoid for Multiplicative<T> {
+        type S = T;
+        fn identity() -> T {
+            T::one()
+        }
+        fn op(a: &T, b: &T) -> T {
+            a.clone() * b.clone()
+        }
+    }
+    // over the nonzero elements, inv of zero fails like division by zero
+    impl<T: Field> Group for Multiplicative<T> {
+        fn inv(a: &T) -> T {
+            T::one() / *a
+        }
+    }
+
+    macro_rules! impl_integer {
+        ($($t:ty),*) => {$(
+            impl Zero for $t {
+                fn zero() -> Self {
+                    0
+                }
+                fn is_zero(&self) -> bool {
+                    *self == 0
+                }
+            }
+            impl One for $t {
+                fn one() -> Self {
+                    1
+                }
+            }
+        )*};
+    }
+    impl_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+    macro_rules! impl_float {
+        ($($t:ty),*) => {$(
+            impl Zero for $t {
+                fn zero() -> Self {
+                    0.0
+                }
+                fn is_zero(&self) -> bool {
+                    *self == 0.0
+                }
+            }
+            impl One for $t {
+                fn one() -> Self {
+                    1.0
+                }
+            }
+            impl Field for $t {
+                fn is_negligible(&self) -> bool {
+                    self.abs() < 1e-9
+                }
+                fn pivot_score(&self) -> f64 {
+                    self.abs() as f64
+                }
+            }
+        )*};
+    }
+    impl_float!(f32, f64);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::algebra::*;
+    use crate::math::bigint::bigint::BigInt;
+    use crate::math::modint::modint::mint998244353;
+    use crate::math::ratio::ratio::Ratio;
+
+    fn power<M: Monoid>(x: &M::S, k: u32) -> M::S {
+        (0..k).fold(M::identity(), |acc, _| M::op(&acc, x))
+    }
+
+    // permutations of 0..3 under composition, a non-commutative group
+    struct Perm3;
+    impl Monoid for Perm3 {
+        type S = [usize; 3];
+        fn identity() -> [usize; 3] {
+            [0, 1, 2]
+        }
+        fn op(a: &[usize; 3], b: &[usize; 3]) -> [usize; 3] {
+            [a[b[0]], a[b[1]], a[b[2]]]
+        }
+    }
+    impl Group for Perm3 {
+        fn inv(a: &[usize; 3]) -> [usize; 3] {
+            let mut r = [0; 3];
+            for (i, &x) in a.iter().enumerate() {
+                r[x] = i;
+            }
+            r
+        }
+    }
+
+    // a^-1 b^-1 a b
+    fn commutator<G: Group>(a: &G::S, b: &G::S) -> G::S {
+        G::op(&G::op(&G::inv(a), &G::inv(b)), &G::op(a, b))
+    }
+
+    fn sum_and_negate<T: AddGroup + Clone>(v: &[T]) -> T {
+        -v.iter().cloned().fold(T::zero(), |acc, x| acc + x)
+    }
+
+    #[test]
+    fn test_algebra() {
+        assert_eq!(power::<Additive<i64>>(&7, 3), 21);
+        assert_eq!(power::<Multiplicative<u64>>(&3, 4), 81);
+        assert_eq!(
+            power::<Multiplicative<mint998244353>>(&2.into(), 30),
+            (1_i64 << 30).into()
+        );
+        assert_eq!(
+            power::<Multiplicative<Ratio>>(&Ratio::new(2, 3), 2),
+            Ratio::new(4, 9)
+        );
+        assert_eq!(
+            power::<Multiplicative<BigInt>>(&BigInt::from(10), 20).to_string(),
+            "100000000000000000000"
+        );
+
+        assert_eq!(commutator::<Additive<i64>>(&3, &5), 0);
+        assert_eq!(
+            commutator::<Multiplicative<Ratio>>(&Ratio::new(2, 3), &Ratio::new(5, 7)),
+            Ratio::new(1, 1)
+        );
+        assert_eq!(
+            Multiplicative::<mint998244353>::inv(&2.into()) * 2.into(),
+            1.into()
+        );
+        let (a, b) = ([1, 0, 2], [0, 2, 1]);
+        assert_eq!(Perm3::op(&a, &Perm3::inv(&a)), Perm3::identity());
+        assert_ne!(Perm3::op(&a, &b), Perm3::op(&b, &a));
+        assert_eq!(commutator::<Perm3>(&a, &b), [2, 0, 1]);
+
+        assert_eq!(sum_and_negate(&[1_i64, 2, 3]), -6);
+        assert_eq!(
+            sum_and_negate(&[Ratio::new(1, 2), Ratio::new(1, 3)]),
+            Ratio::new(-5, 6)
+        );
+        assert_eq!(sum_and_negate::<BigInt>(&[]), BigInt::zero());
+
+        assert!(Ratio::<i64>::zero().is_zero() && !Ratio::<i64>::one().is_zero());
+        assert!(mint998244353::new(998244353).is_zero());
+        assert!(1e-12_f64.is_negligible() && !1e-12_f64.is_zero());
+    }
+}
@@ -0,0 +1,608 @@
+pub mod bigint {
+    use crate::math::algebra::algebra::{One, Zero};
+    use crate::math::ratio::ratio::Integer;
+    use std::cmp::Ordering;
+    use std::ops::*;
+    use std::str::FromStr;
+
+    const BASE: u32 = 1_000_000_000;
+    const WIDTH: usize = 9;
+    const KARATSUBA_THRESHOLD: usize = 32;
+
+    fn trim(mut d: Vec<u32>) -> Vec<u32> {
+        while d.last() == Some(&0) {
+            d.pop();
+        }
+        d
+    }
+
+    fn trimmed(d: &[u32]) -> &[u32] {
+        let n = d.iter().rposition(|&x| x != 0).map_or(0, |i| i + 1);
+        &d[..n]
+    }
+
+    fn cmp_mag(a: &[u32], b: &[u32]) -> Ordering {
+        a.len()
+            .cmp(&b.len())
+            .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+    }
+
+    // c[shift..] += x, c must be long enough to hold the carry
+    fn add_into(c: &mut [u32], x: &[u32], shift: usize) {
+        let mut carry = 0;
+        let mut i = shift;
+        for &y in x {
+            let s = c[i] + y + carry;
+            (c[i], carry) = if s >= BASE { (s - BASE, 1) } else { (s, 0) };
+            i += 1;
+        }
+        while carry > 0 {
+            let s = c[i] + carry;
+            (c[i], carry) = if s >= BASE { (s - BASE, 1) } else { (s, 0) };
+            i += 1;
+        }
+    }
+
+    fn add_mag(a: &[u32], b: &[u32]) -> Vec<u32> {
+        let (a, b) = if a.len() < b.len() { (b, a) } else { (a, b) };
+        let mut c = a.to_vec();
+        c.push(0);
+        add_into(&mut c, b, 0);
+        trim(c)
+    }
+
+    // a - b, requires a >= b
+    fn sub_mag(a: &[u32], b: &[u32]) -> Vec<u32> {
+        let mut c = a.to_vec();
+        let mut borrow = 0;
+        for (i, x) in c.iter_mut().enumerate() {
+            if i >= b.len() && borrow == 0 {
+                break;
+            }
+            let y = b.get(i).copied().unwrap_or(0) + borrow;
+            (*x, borrow) = if *x >= y {
+                (*x - y, 0)
+            } else {
+                (*x + BASE - y, 1)
+            };
+        }
+        assert_eq!(borrow, 0, "attempt to subtract with overflow");
+        trim(c)
+    }
+
+    fn mul_small(a: &[u32], m: u32) -> Vec<u32> {
+        let mut c = Vec::with_capacity(a.len() + 1);
+        let mut carry = 0_u64;
+        for &x in a {
+            let cur = x as u64 * m as u64 + carry;
+            c.push((cur % BASE as u64) as u32);
+            carry = cur / BASE as u64;
+        }
+        c.push(carry as u32);
+        trim(c)
+    }
+
+    fn mul_schoolbook(a: &[u32], b: &[u32]) -> Vec<u32> {
+        let mut c = vec![0_u32; a.len() + b.len()];
+        for (i, &x) in a.iter().enumerate() {
+            let mut carry = 0_u64;
+            for (j, &y) in b.iter().enumerate() {
+                let cur = c[i + j] as u64 + x as u64 * y as u64 + carry;
+                c[i + j] = (cur % BASE as u64) as u32;
+                carry = cur / BASE as u64;
+            }
+            c[i + b.len()] = carry as u32;
+        }
+        trim(c)
+    }
+
+    fn mul_mag(a: &[u32], b: &[u32]) -> Vec<u32> {
+        let (a, b) = (trimmed(a), trimmed(b));
+        if a.is_empty() || b.is_empty() {
+            return vec![];
+        }
+        if a.len().min(b.len()) < KARATSUBA_THRESHOLD {
+            return mul_schoolbook(a, b);
+        }
+        let m = a.len().max(b.len()) / 2;
+        let (a0, a1) = a.split_at(m.min(a.len()));
+        let (b0, b1) = b.split_at(m.min(b.len()));
+        let z0 = mul_mag(a0, b0);
+        let z2 = mul_mag(a1, b1);
+        let z1 = mul_mag(&add_mag(a0, a1), &add_mag(b0, b1));
+        let z1 = sub_mag(&sub_mag(&z1, &z0), &z2);
+        let mut c = vec![0; a.len() + b.len() + 1];
+        add_into(&mut c, &z0, 0);
+        add_into(&mut c, &z1, m);
+        add_into(&mut c, &z2, 2 * m);
+        trim(c)
+    }
+
+    fn div_rem_mag(a: &[u32], b: &[u32]) -> (Vec<u32>, Vec<u32>) {
+        assert!(!b.is_empty(), "attempt to divide by zero");
+        if cmp_mag(a, b) == Ordering::Less {
+            return (vec![], a.to_vec());
+        }
+        let mut q = vec![0; a.len()];
+        if b.len() == 1 {
+            let mut r = 0_u64;
+            for i in (0..a.len()).rev() {
+                let cur = r * BASE as u64 + a[i] as u64;
+                q[i] = (cur / b[0] as u64) as u32;
+                r = cur % b[0] as u64;
+            }
+            return (trim(q), trim(vec![r as u32]));
+        }
+        let mut r: Vec<u32> = vec![];
+        for i in (0..a.len()).rev() {
+            r.insert(0, a[i]);
+            r = trim(r);
+            if cmp_mag(&r, b) == Ordering::Less {
+                continue;
+            }
+            // largest digit d with b * d <= r, bracketed using the limbs from b.len() - 2 up
+            let k = b.len() - 2;
+            let top = |x: &[u32]| -> u128 {
+                x[k..]
+                    .iter()
+                    .rev()
+                    .fold(0, |acc, &y| acc * BASE as u128 + y as u128)
+            };
+            let (rt, bt) = (top(&r), top(b));
+            let (mut lo, mut hi) = (
+                (rt / (bt + 1)).min(BASE as u128 - 1) as u32,
+                ((rt + 1) / bt).min(BASE as u128 - 1) as u32,
+            );
+            while lo < hi {
+                let mid = lo + (hi - lo).div_ceil(2);
+                if cmp_mag(&mul_small(b, mid), &r) == Ordering::Greater {
+                    hi = mid - 1;
+                } else {
+                    lo = mid;
+                }
+            }
+            r = sub_mag(&r, &mul_small(b, lo));
+            q[i] = lo;
+        }
+        (trim(q), r)
+    }
+
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct ParseBigIntError;
+
+    impl std::fmt::Display for ParseBigIntError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "invalid digit found in string")
+        }
+    }
+
+    // little-endian limbs in base 10^9 without trailing zeros, zero is empty
+    #[derive(Clone, PartialEq, Eq, Hash, Debug, Default)]
+    pub struct BigUint {
+        d: Vec<u32>,
+    }
+
+    impl BigUint {
+        pub fn zero() -> Self {
+            BigUint { d: vec![] }
+        }
+
+        pub fn is_zero(&self) -> bool {
+            self.d.is_empty()
+        }
+
+        pub fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+            let (q, r) = div_rem_mag(&self.d, &rhs.d);
+            (BigUint { d: q }, BigUint { d: r })
+        }
+
+        pub fn pow(&self, mut k: u32) -> Self {
+            let mut x = self.clone();
+            let mut ans = BigUint::from(1_u32);
+            while k != 0 {
+                if k & 1 == 1 {
+                    ans = &ans * &x;
+                }
+                x = &x * &x;
+                k >>= 1;
+            }
+            ans
+        }
+
+        pub fn to_f64(&self) -> f64 {
+            self.d
+                .iter()
+                .rev()
+                .fold(0.0, |acc, &x| acc * BASE as f64 + x as f64)
+        }
+    }
+
+    impl Ord for BigUint {
+        fn cmp(&self, other: &Self) -> Ordering {
+            cmp_mag(&self.d, &other.d)
+        }
+    }
+    impl PartialOrd for BigUint {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Add<&BigUint> for &BigUint {
+        type Output = BigUint;
+        fn add(self, rhs: &BigUint) -> BigUint {
+            BigUint {
+                d: add_mag(&self.d, &rhs.d),
+            }
+        }
+    }
+    impl Sub<&BigUint> for &BigUint {
+        type Output = BigUint;
+        fn sub(self, rhs: &BigUint) -> BigUint {
+            BigUint {
+                d: sub_mag(&self.d, &rhs.d),
+            }
+        }
+    }
+    impl Mul<&BigUint> for &BigUint {
+        type Output = BigUint;
+        fn mul(self, rhs: &BigUint) -> BigUint {
+            BigUint {
+                d: mul_mag(&self.d, &rhs.d),
+            }
+        }
+    }
+    impl Div<&BigUint> for &BigUint {
+        type Output = BigUint;
+        fn div(self, rhs: &BigUint) -> BigUint {
+            self.div_rem(rhs).0
+        }
+    }
+    impl Rem<&BigUint> for &BigUint {
+        type Output = BigUint;
+        fn rem(self, rhs: &BigUint) -> BigUint {
+            self.div_rem(rhs).1
+        }
+    }
+
+    // by-value and compound-assignment operators in terms of the by-reference ones
+    macro_rules! forward_ops {
+        ($t:ty; $($tr:ident $f:ident $atr:ident $af:ident),*) => {$(
+            impl $tr for $t {
+                type Output = $t;
+                fn $f(self, rhs: $t) -> $t {
+                    $tr::$f(&self, &rhs)
+                }
+            }
+            impl $atr for $t {
+                fn $af(&mut self, rhs: $t) {
+                    *self = $tr::$f(&*self, &rhs);
+                }
+            }
+        )*};
+    }
+    forward_ops!(BigUint; Add add AddAssign add_assign, Sub sub SubAssign sub_assign,
+        Mul mul MulAssign mul_assign, Div div DivAssign div_assign, Rem rem RemAssign rem_assign);
+
+    impl FromStr for BigUint {
+        type Err = ParseBigIntError;
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let s = s.strip_prefix('+').unwrap_or(s);
+            if s.is_empty() || !s.bytes().all(|c| c.is_ascii_digit()) {
+                return Err(ParseBigIntError);
+            }
+            let d = s
+                .as_bytes()
+                .rchunks(WIDTH)
+                .map(|chunk| chunk.iter().fold(0, |acc, &c| acc * 10 + (c - b'0') as u32))
+                .collect();
+            Ok(BigUint { d: trim(d) })
+        }
+    }
+
+    impl std::fmt::Display for BigUint {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            let Some((last, rest)) = self.d.split_last() else {
+                return write!(f, "0");
+            };
+            write!(f, "{}", last)?;
+            for x in rest.iter().rev() {
+                write!(f, "{:09}", x)?;
+            }
+            Ok(())
+        }
+    }
+
+    macro_rules! from_unsigned {
+        ($($t:ty),*) => {$(
+            impl From<$t> for BigUint {
+                fn from(value: $t) -> Self {
+                    let mut v = value as u128;
+                    let mut d = vec![];
+                    while v > 0 {
+                        d.push((v % BASE as u128) as u32);
+                        v /= BASE as u128;
+                    }
+                    BigUint { d }
+                }
+            }
+        )*};
+    }
+    from_unsigned!(u8, u16, u32, u64, u128, usize);
+
+    // sign and magnitude, zero is never negative
+    #[derive(Clone, PartialEq, Eq, Hash, Debug, Default)]
+    pub struct BigInt {
+        neg: bool,
+        mag: BigUint,
+    }
+
+    impl BigInt {
+        pub fn from_parts(neg: bool, mag: BigUint) -> Self {
+            BigInt {
+                neg: neg && !mag.is_zero(),
+                mag,
+            }
+        }
+
+        pub fn zero() -> Self {
+            BigInt::default()
+        }
+
+        pub fn is_zero(&self) -> bool {
+            self.mag.is_zero()
+        }
+
+        pub fn is_negative(&self) -> bool {
+            self.neg
+        }
+
+        pub fn magnitude(&self) -> &BigUint {
+            &self.mag
+        }
+
+        pub fn abs(&self) -> Self {
+            BigInt::from_parts(false, self.mag.clone())
+        }
+
+        // truncated towards zero like the primitive integers
+        pub fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+            let (q, r) = self.mag.div_rem(&rhs.mag);
+            (
+                BigInt::from_parts(self.neg != rhs.neg, q),
+                BigInt::from_parts(self.neg, r),
+            )
+        }
+
+        pub fn pow(&self, k: u32) -> Self {
+            BigInt::from_parts(self.neg && k & 1 == 1, self.mag.pow(k))
+        }
+
+        pub fn to_f64(&self) -> f64 {
+            if self.neg {
+                -self.mag.to_f64()
+            } else {
+                self.mag.to_f64()
+            }
+        }
+    }
+
+    impl Ord for BigInt {
+        fn cmp(&self, other: &Self) -> Ordering {
+            match (self.neg, other.neg) {
+                (false, false) => self.mag.cmp(&other.mag),
+                (true, true) => other.mag.cmp(&self.mag),
+                (false, true) => Ordering::Greater,
+                (true, false) => Ordering::Less,
+            }
+        }
+    }
+    impl PartialOrd for BigInt {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Neg for &BigInt {
+        type Output = BigInt;
+        fn neg(self) -> BigInt {
+            BigInt::from_parts(!self.neg, self.mag.clone())
+        }
+    }
+    impl Neg for BigInt {
+        type Output = BigInt;
+        fn neg(self) -> BigInt {
+            BigInt::from_parts(!self.neg, self.mag)
+        }
+    }
+
+    impl Add<&BigInt> for &BigInt {
+        type Output = BigInt;
+        fn add(self, rhs: &BigInt) -> BigInt {
+            if self.neg == rhs.neg {
+                return BigInt::from_parts(self.neg, &self.mag + &rhs.mag);
+            }
+            match self.mag.cmp(&rhs.mag) {
+                Ordering::Less => BigInt::from_parts(rhs.neg, &rhs.mag - &self.mag),
+                _ => BigInt::from_parts(self.neg, &self.mag - &rhs.mag),
+            }
+        }
+    }
+    impl Sub<&BigInt> for &BigInt {
+        type Output = BigInt;
+        fn sub(self, rhs: &BigInt) -> BigInt {
+            self + &-rhs
+        }
+    }
+    impl Mul<&BigInt> for &BigInt {
+        type Output = BigInt;
+        fn mul(self, rhs: &BigInt) -> BigInt {
+            BigInt::from_parts(self.neg != rhs.neg, &self.mag * &rhs.mag)
+        }
+    }
+    impl Div<&BigInt> for &BigInt {
+        type Output = BigInt;
+        fn div(self, rhs: &BigInt) -> BigInt {
+            self.div_rem(rhs).0
+        }
+    }
+    impl Rem<&BigInt> for &BigInt {
+        type Output = BigInt;
+        fn rem(self, rhs: &BigInt) -> BigInt {
+            self.div_rem(rhs).1
+        }
+    }
+    forward_ops!(BigInt; Add add AddAssign add_assign, Sub sub SubAssign sub_assign,
+        Mul mul MulAssign mul_assign, Div div DivAssign div_assign, Rem rem RemAssign rem_assign);
+
+    impl FromStr for BigInt {
+        type Err = ParseBigIntError;
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            match s.strip_prefix('-') {
+                Some(rest) if !rest.starts_with('+') => Ok(BigInt::from_parts(true, rest.parse()?)),
+                Some(_) => Err(ParseBigIntError),
+                None => Ok(BigInt::from_parts(false, s.parse()?)),
+            }
+        }
+    }
+
+    impl std::fmt::Display for BigInt {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            if self.neg {
+                write!(f, "-")?;
+            }
+            write!(f, "{}", self.mag)
+        }
+    }
+
+    impl From<BigUint> for BigInt {
+        fn from(value: BigUint) -> Self {
+            BigInt::from_parts(false, value)
+        }
+    }
+
+    macro_rules! from_signed {
+        ($($t:ty),*) => {$(
+            impl From<$t> for BigInt {
+                fn from(value: $t) -> Self {
+                    BigInt::from_parts(value < 0, BigUint::from(value.unsigned_abs()))
+                }
+            }
+        )*};
+    }
+    from_signed!(i8, i16, i32, i64, i128, isize);
+
+    macro_rules! from_unsigned_signed {
+        ($($t:ty),*) => {$(
+            impl From<$t> for BigInt {
+                fn from(value: $t) -> Self {
+                    BigInt::from_parts(false, BigUint::from(value))
+                }
+            }
+        )*};
+    }
+    from_unsigned_signed!(u8, u16, u32, u64, u128, usize);
+
+    impl Zero for BigUint {
+        fn zero() -> Self {
+            BigUint::zero()
+        }
+        fn is_zero(&self) -> bool {
+            self.is_zero()
+        }
+    }
+    impl One for BigUint {
+        fn one() -> Self {
+            BigUint::from(1_u32)
+        }
+    }
+    impl Zero for BigInt {
+        fn zero() -> Self {
+            BigInt::zero()
+        }
+        fn is_zero(&self) -> bool {
+            self.is_zero()
+        }
+    }
+    impl One for BigInt {
+        fn one() -> Self {
+            BigInt::from(1)
+        }
+    }
+
+    impl Integer for BigInt {
+        fn to_f64(&self) -> f64 {
+            self.to_f64()
+        }
+        fn cmp_fraction(a: &Self, b: &Self, c: &Self, d: &Self) -> Ordering {
+            (a * d).cmp(&(c * b))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::bigint::*;
+    use crate::test_util::test_util::XorShift;
+
+    fn random_digits(n: usize, rng: &mut XorShift) -> String {
+        let mut s: String = (0..n)
+            .map(|_| char::from(b'0' + rng.below(10) as u8))
+            .collect();
+        s.insert(0, char::from(b'1' + rng.below(9) as u8));
+        s
+    }
+
+    #[test]
+    fn test_bigint_small() {
+        let mut rng = XorShift::default();
+        for _ in 0..2000 {
+            let a = (rng.next_u64() as i64 >> rng.below(64)) as i128;
+            let b = (rng.next_u64() as i64 >> rng.below(64)) as i128;
+            let (x, y) = (BigInt::from(a), BigInt::from(b));
+            assert_eq!(x.to_string(), a.to_string());
+            assert_eq!(x.to_string().parse::<BigInt>(), Ok(x.clone()));
+            assert_eq!(&x + &y, BigInt::from(a + b));
+            assert_eq!(&x - &y, BigInt::from(a - b));
+            assert_eq!(&x * &y, BigInt::from(a * b));
+            assert_eq!(x.cmp(&y), a.cmp(&b));
+            if b != 0 {
+                assert_eq!(&x / &y, BigInt::from(a / b));
+                assert_eq!(&x % &y, BigInt::from(a % b));
+            }
+        }
+        assert_eq!(BigInt::from(-0_i32).to_string(), "0");
+        assert_eq!(BigUint::from(u128::MAX).to_string(), u128::MAX.to_string());
+        assert_eq!("-00012".parse::<BigInt>(), Ok(BigInt::from(-12)));
+        assert!("".parse::<BigInt>().is_err());
+        assert!("-".parse::<BigInt>().is_err());
+        assert!("-+1".parse::<BigInt>().is_err());
+        assert!("12a".parse::<BigUint>().is_err());
+    }
+
+    #[test]
+    fn test_bigint_large() {
+        let fact = (1..=30_u32).fold(BigUint::from(1_u32), |acc, i| acc * BigUint::from(i));
+        assert_eq!(fact.to_string(), "265252859812191058636308480000000");
+        assert_eq!(
+            BigUint::from(2_u32).pow(200).to_string(),
+            "1606938044258990275541962092341162602522202993782792835301376"
+        );
+
+        let mut rng = XorShift::default();
+        for (n, m) in [(20, 15), (400, 380), (1500, 700), (3000, 40)] {
+            let a: BigInt = random_digits(n, &mut rng).parse().unwrap();
+            let b: BigInt = random_digits(m, &mut rng).parse().unwrap();
+            let b = -b;
+            let sq = &(&a + &b) * &(&a + &b);
+            let expanded = &(&(&a * &a) + &(&b * &b)) + &(&(&a * &b) * &BigInt::from(2));
+            assert_eq!(sq, expanded);
+            let (q, r) = a.div_rem(&b);
+            assert_eq!(&(&q * &b) + &r, a);
+            assert!(r.abs() < b.abs() && !r.is_negative());
+            assert_eq!(&(&a * &b) / &b, a);
+            assert!((&(&a * &b) % &a).is_zero());
+            assert!(b < a && -&a < b);
+        }
+    }
+}
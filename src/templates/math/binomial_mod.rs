@@ -0,0 +1,201 @@
+pub mod binomial_mod {
+    use crate::math::number_theory::number_theory::{crt, inv_mod};
+    use crate::math::prime::prime::factorize;
+    use crate::math::qpow::qpow::{mul_mod_u64, qpow_u64};
+
+    // C(n, k) mod p for a small prime p by Lucas's theorem, O(p) to build
+    #[derive(Clone, Debug)]
+    pub struct Lucas {
+        p: u64,
+        fact: Vec<u64>,
+        inv_fact: Vec<u64>,
+    }
+
+    impl Lucas {
+        pub fn new(p: u64) -> Self {
+            assert!(p >= 2);
+            let mut fact = vec![1_u64; p as usize];
+            for i in 1..p as usize {
+                fact[i] = mul_mod_u64(fact[i - 1], i as u64, p);
+            }
+            let mut inv_fact = vec![1_u64; p as usize];
+            inv_fact[p as usize - 1] = qpow_u64(fact[p as usize - 1], p - 2, p);
+            for i in (1..p as usize).rev() {
+                inv_fact[i - 1] = mul_mod_u64(inv_fact[i], i as u64, p);
+            }
+            Lucas { p, fact, inv_fact }
+        }
+
+        fn binom_small(&self, n: u64, k: u64) -> u64 {
+            if k > n {
+                return 0;
+            }
+            let (n, k) = (n as usize, k as usize);
+            mul_mod_u64(
+                mul_mod_u64(self.fact[n], self.inv_fact[k], self.p),
+                self.inv_fact[n - k],
+                self.p,
+            )
+        }
+
+        pub fn binom(&self, mut n: u64, mut k: u64) -> u64 {
+            let mut ans = 1 % self.p;
+            while k > 0 && ans != 0 {
+                ans = mul_mod_u64(ans, self.binom_small(n % self.p, k % self.p), self.p);
+                n /= self.p;
+                k /= self.p;
+            }
+            ans
+        }
+    }
+
+    // C(n, k) mod p^e by Granville's theorem, O(p^e) to build
+    #[derive(Clone, Debug)]
+    pub struct BinomialPrimePower {
+        p: u64,
+        e: u32,
+        pe: u64,
+        // prod[i] = product of 1..=i coprime to p, mod p^e
+        prod: Vec<u64>,
+    }
+
+    impl BinomialPrimePower {
+        pub fn new(p: u64, e: u32) -> Self {
+            assert!(p >= 2 && e >= 1);
+            let pe = p.pow(e);
+            let mut prod = vec![1_u64; pe as usize + 1];
+            for i in 1..=pe {
+                prod[i as usize] = if i % p == 0 {
+                    prod[i as usize - 1]
+                } else {
+                    mul_mod_u64(prod[i as usize - 1], i, pe)
+                };
+            }
+            prod[0] = 1 % pe;
+            BinomialPrimePower { p, e, pe, prod }
+        }
+
+        pub fn modulus(&self) -> u64 {
+            self.pe
+        }
+
+        // (n! with every factor p removed mod p^e, exponent of p in n!)
+        fn factorial(&self, mut n: u64) -> (u64, u64) {
+            let (mut r, mut v) = (1 % self.pe, 0);
+            while n > 0 {
+                let full = qpow_u64(self.prod[self.pe as usize], n / self.pe, self.pe);
+                r = mul_mod_u64(r, full, self.pe);
+                r = mul_mod_u64(r, self.prod[(n % self.pe) as usize], self.pe);
+                n /= self.p;
+                v += n;
+            }
+            (r, v)
+        }
+
+        pub fn binom(&self, n: u64, k: u64) -> u64 {
+            if k > n {
+                return 0;
+            }
+            let (a, va) = self.factorial(n);
+            let (b, vb) = self.factorial(k);
+            let (c, vc) = self.factorial(n - k);
+            let v = va - vb - vc;
+            if v >= self.e as u64 {
+                return 0;
+            }
+            let den = mul_mod_u64(b, c, self.pe);
+            let inv = inv_mod(den as i64, self.pe as i64).unwrap() as u64;
+            let ans = mul_mod_u64(a, inv, self.pe);
+            mul_mod_u64(ans, self.p.pow(v as u32), self.pe)
+        }
+    }
+
+    // C(n, k) mod any m, one prime power at a time joined by CRT
+    #[derive(Clone, Debug)]
+    pub struct BinomialMod {
+        m: u64,
+        parts: Vec<BinomialPrimePower>,
+    }
+
+    impl BinomialMod {
+        pub fn new(m: u64) -> Self {
+            assert!(m >= 1);
+            let parts = if m == 1 {
+                vec![]
+            } else {
+                factorize(m)
+                    .into_iter()
+                    .map(|(p, e)| BinomialPrimePower::new(p, e))
+                    .collect()
+            };
+            BinomialMod { m, parts }
+        }
+
+        pub fn binom(&self, n: u64, k: u64) -> u64 {
+            let congruences: Vec<(i64, i64)> = self
+                .parts
+                .iter()
+                .map(|part| (part.binom(n, k) as i64, part.modulus() as i64))
+                .collect();
+            let (x, _) = crt(&congruences).unwrap();
+            x as u64 % self.m
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::binomial_mod::*;
+
+    fn pascal(n: usize, m: u64) -> Vec<Vec<u64>> {
+        let mut c = vec![vec![0_u64; n + 1]; n + 1];
+        for i in 0..=n {
+            c[i][0] = 1 % m;
+            for j in 1..=i {
+                c[i][j] = (c[i - 1][j - 1] + c[i - 1][j]) % m;
+            }
+        }
+        c
+    }
+
+    #[test]
+    fn test_lucas() {
+        for p in [2, 3, 5, 7, 13] {
+            let lucas = Lucas::new(p);
+            let c = pascal(200, p);
+            for (n, row) in c.iter().enumerate() {
+                for (k, &x) in row.iter().enumerate().take(n + 1) {
+                    assert_eq!(lucas.binom(n as u64, k as u64), x);
+                }
+                assert_eq!(lucas.binom(n as u64, n as u64 + 1), 0);
+            }
+        }
+        let lucas = Lucas::new(1_000_003);
+        assert_eq!(lucas.binom(1_000_003 * 5 + 2, 1_000_003 * 3 + 1), 10 * 2);
+    }
+
+    #[test]
+    fn test_binomial_mod() {
+        for m in [1, 2, 8, 9, 27, 12, 100, 720, 1001, 1024] {
+            let binom = BinomialMod::new(m);
+            let c = pascal(150, m);
+            for (n, row) in c.iter().enumerate() {
+                for (k, &x) in row.iter().enumerate().take(n + 1) {
+                    assert_eq!(binom.binom(n as u64, k as u64), x);
+                }
+            }
+        }
+        let binom = BinomialMod::new(1_000_000);
+        let c = pascal(300, 1_000_000);
+        for (n, row) in c.iter().enumerate().step_by(7) {
+            for (k, &x) in row.iter().enumerate().take(n + 1) {
+                assert_eq!(binom.binom(n as u64, k as u64), x);
+            }
+        }
+        // C(10^18, 10^9) mod 2^6 * 5^6 agrees with the prime power parts
+        let (n, k) = (1_000_000_000_000_000_000, 1_000_000_000);
+        let x = binom.binom(n, k);
+        assert_eq!(x % 64, BinomialPrimePower::new(2, 6).binom(n, k));
+        assert_eq!(x % 15625, BinomialPrimePower::new(5, 6).binom(n, k));
+    }
+}
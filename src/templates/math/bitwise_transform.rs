@@ -0,0 +1,229 @@
+pub mod bitwise_transform {
+    use crate::math::algebra::algebra::Ring;
+    use crate::math::modint::modint::ModInt;
+
+    pub trait DivExact: Ring {
+        // self / n, only called where the quotient is exact
+        fn div_exact(self, n: usize) -> Self;
+    }
+
+    macro_rules! impl_div_exact {
+        ($($t:ty),*) => {$(
+            impl DivExact for $t {
+                fn div_exact(self, n: usize) -> Self {
+                    self / n as $t
+                }
+            }
+        )*};
+    }
+    // unsigned types are left out, the transform goes through negative values
+    impl_div_exact!(i8, i16, i32, i64, i128, isize);
+
+    impl<const P: i32> DivExact for ModInt<P> {
+        fn div_exact(self, n: usize) -> Self {
+            self / ModInt::new(n as i64)
+        }
+    }
+
+    fn butterfly<T: Ring>(a: &mut [T], mut f: impl FnMut(&mut T, &mut T)) {
+        let n = a.len();
+        assert!(n.is_power_of_two());
+        let mut len = 1;
+        while len < n {
+            for chunk in a.chunks_mut(2 * len) {
+                let (lo, hi) = chunk.split_at_mut(len);
+                for (x, y) in lo.iter_mut().zip(hi.iter_mut()) {
+                    f(x, y);
+                }
+            }
+            len <<= 1;
+        }
+    }
+
+    pub fn xor_transform<T: DivExact>(a: &mut [T], inverse: bool) {
+        butterfly(a, |x, y| (*x, *y) = (*x + *y, *x - *y));
+        if inverse {
+            let n = a.len();
+            for x in a.iter_mut() {
+                *x = x.div_exact(n);
+            }
+        }
+    }
+
+    // a[s] becomes the sum of a[t] over supersets t of s
+    pub fn superset_zeta<T: Ring>(a: &mut [T]) {
+        butterfly(a, |x, y| *x = *x + *y);
+    }
+
+    pub fn superset_mobius<T: Ring>(a: &mut [T]) {
+        butterfly(a, |x, y| *x = *x - *y);
+    }
+
+    // a[s] becomes the sum of a[t] over subsets t of s
+    pub fn subset_zeta<T: Ring>(a: &mut [T]) {
+        butterfly(a, |x, y| *y = *y + *x);
+    }
+
+    pub fn subset_mobius<T: Ring>(a: &mut [T]) {
+        butterfly(a, |x, y| *y = *y - *x);
+    }
+
+    pub fn and_transform<T: Ring>(a: &mut [T], inverse: bool) {
+        if inverse {
+            superset_mobius(a);
+        } else {
+            superset_zeta(a);
+        }
+    }
+
+    pub fn or_transform<T: Ring>(a: &mut [T], inverse: bool) {
+        if inverse {
+            subset_mobius(a);
+        } else {
+            subset_zeta(a);
+        }
+    }
+
+    fn convolve<T: Ring>(a: &[T], b: &[T], transform: fn(&mut [T], bool)) -> Vec<T> {
+        assert_eq!(a.len(), b.len());
+        let (mut a, mut b) = (a.to_vec(), b.to_vec());
+        transform(&mut a, false);
+        transform(&mut b, false);
+        for (x, &y) in a.iter_mut().zip(b.iter()) {
+            *x = *x * y;
+        }
+        transform(&mut a, true);
+        a
+    }
+
+    // c[k] = sum of a[i] * b[j] over i ^ j = k
+    pub fn xor_convolution<T: DivExact>(a: &[T], b: &[T]) -> Vec<T> {
+        convolve(a, b, xor_transform)
+    }
+
+    pub fn and_convolution<T: Ring>(a: &[T], b: &[T]) -> Vec<T> {
+        convolve(a, b, and_transform)
+    }
+
+    pub fn or_convolution<T: Ring>(a: &[T], b: &[T]) -> Vec<T> {
+        convolve(a, b, or_transform)
+    }
+
+    // c[k] = sum of a[i] * b[k ^ i] over subsets i of k, in O(n^2 2^n)
+    pub fn subset_convolution<T: Ring>(a: &[T], b: &[T]) -> Vec<T> {
+        assert_eq!(a.len(), b.len());
+        let size = a.len();
+        assert!(size.is_power_of_two());
+        let n = size.trailing_zeros() as usize;
+        let ranked = |a: &[T]| -> Vec<Vec<T>> {
+            let mut r = vec![vec![T::zero(); size]; n + 1];
+            for (s, &x) in a.iter().enumerate() {
+                r[s.count_ones() as usize][s] = x;
+            }
+            for row in r.iter_mut() {
+                subset_zeta(row);
+            }
+            r
+        };
+        let (ra, rb) = (ranked(a), ranked(b));
+        let mut rc = vec![vec![T::zero(); size]; n + 1];
+        for (k, row) in rc.iter_mut().enumerate() {
+            for i in 0..=k {
+                for (c, (&x, &y)) in row.iter_mut().zip(ra[i].iter().zip(rb[k - i].iter())) {
+                    *c = *c + x * y;
+                }
+            }
+            subset_mobius(row);
+        }
+        (0..size).map(|s| rc[s.count_ones() as usize][s]).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::bitwise_transform::*;
+    use crate::math::modint::modint::{mint998244353, ModInt};
+    use crate::test_util::test_util::XorShift;
+
+    fn random(n: usize, rng: &mut XorShift) -> Vec<i64> {
+        (0..n).map(|_| rng.range(-1000, 1000)).collect()
+    }
+
+    fn brute(a: &[i64], b: &[i64], keep: impl Fn(usize, usize) -> Option<usize>) -> Vec<i64> {
+        let mut c = vec![0; a.len()];
+        for (i, &x) in a.iter().enumerate() {
+            for (j, &y) in b.iter().enumerate() {
+                if let Some(k) = keep(i, j) {
+                    c[k] += x * y;
+                }
+            }
+        }
+        c
+    }
+
+    #[test]
+    fn test_bitwise_convolution() {
+        let mut rng = XorShift::default();
+        for n in 0..7 {
+            let a = random(1 << n, &mut rng);
+            let b = random(1 << n, &mut rng);
+            assert_eq!(xor_convolution(&a, &b), brute(&a, &b, |i, j| Some(i ^ j)));
+            assert_eq!(and_convolution(&a, &b), brute(&a, &b, |i, j| Some(i & j)));
+            assert_eq!(or_convolution(&a, &b), brute(&a, &b, |i, j| Some(i | j)));
+            let expected = brute(&a, &b, |i, j| (i & j == 0).then_some(i | j));
+            assert_eq!(subset_convolution(&a, &b), expected);
+
+            let to_mint =
+                |v: &[i64]| -> Vec<mint998244353> { v.iter().map(|&x| ModInt::new(x)).collect() };
+            let (ma, mb) = (to_mint(&a), to_mint(&b));
+            assert_eq!(
+                xor_convolution(&ma, &mb),
+                to_mint(&brute(&a, &b, |i, j| Some(i ^ j)))
+            );
+            assert_eq!(subset_convolution(&ma, &mb), to_mint(&expected));
+
+            let a128: Vec<i128> = a.iter().map(|&x| x as i128).collect();
+            let b128: Vec<i128> = b.iter().map(|&x| x as i128).collect();
+            let c = brute(&a, &b, |i, j| Some(i ^ j));
+            let c128: Vec<i128> = c.iter().map(|&x| x as i128).collect();
+            assert_eq!(xor_convolution(&a128, &b128), c128);
+            // small values so the transformed products fit in i32
+            let (a, b): (Vec<i64>, Vec<i64>) = (
+                a.iter().map(|x| x % 100).collect(),
+                b.iter().map(|x| x % 100).collect(),
+            );
+            let a32: Vec<i32> = a.iter().map(|&x| x as i32).collect();
+            let b32: Vec<i32> = b.iter().map(|&x| x as i32).collect();
+            let c32: Vec<i32> = brute(&a, &b, |i, j| Some(i ^ j))
+                .iter()
+                .map(|&x| x as i32)
+                .collect();
+            assert_eq!(xor_convolution(&a32, &b32), c32);
+        }
+    }
+
+    #[test]
+    fn test_zeta_mobius() {
+        let mut rng = XorShift::default();
+        let a = random(1 << 5, &mut rng);
+        let mut sub = a.clone();
+        let mut sup = a.clone();
+        subset_zeta(&mut sub);
+        superset_zeta(&mut sup);
+        for s in 0..a.len() {
+            let below: i64 = (0..a.len()).filter(|&t| t & s == t).map(|t| a[t]).sum();
+            let above: i64 = (0..a.len()).filter(|&t| t & s == s).map(|t| a[t]).sum();
+            assert_eq!(sub[s], below);
+            assert_eq!(sup[s], above);
+        }
+        subset_mobius(&mut sub);
+        superset_mobius(&mut sup);
+        assert_eq!(sub, a);
+        assert_eq!(sup, a);
+
+        let mut x = a.clone();
+        xor_transform(&mut x, false);
+        xor_transform(&mut x, true);
+        assert_eq!(x, a);
+    }
+}
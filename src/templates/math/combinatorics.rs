@@ -0,0 +1,129 @@
+pub mod combinatorics {
+    use crate::math::modint::modint::ModInt;
+
+    #[derive(Clone, Debug)]
+    pub struct Factorials<const P: i32> {
+        fact: Vec<ModInt<P>>,
+        inv_fact: Vec<ModInt<P>>,
+    }
+
+    impl<const P: i32> Default for Factorials<P> {
+        fn default() -> Self {
+            Self::new(1)
+        }
+    }
+
+    impl<const P: i32> Factorials<P> {
+        pub fn new(n: usize) -> Self {
+            let mut f = Factorials {
+                fact: vec![ModInt::new(1)],
+                inv_fact: vec![ModInt::new(1)],
+            };
+            f.ensure(n);
+            f
+        }
+
+        // tables grow by doubling so lookups past n are amortized O(1)
+        pub fn ensure(&mut self, n: usize) {
+            let len = self.fact.len();
+            if n < len {
+                return;
+            }
+            assert!(n < P as usize, "factorial of {} vanishes modulo {}", n, P);
+            let m = (2 * len).max(n + 1).min(P as usize);
+            for i in len..m {
+                let x = self.fact[i - 1] * ModInt::new(i as i64);
+                self.fact.push(x);
+            }
+            self.inv_fact.resize(m, ModInt::default());
+            self.inv_fact[m - 1] = self.fact[m - 1].inv();
+            for i in (len..m - 1).rev() {
+                self.inv_fact[i] = self.inv_fact[i + 1] * ModInt::new((i + 1) as i64);
+            }
+        }
+
+        pub fn fact(&mut self, n: usize) -> ModInt<P> {
+            self.ensure(n);
+            self.fact[n]
+        }
+
+        pub fn inv_fact(&mut self, n: usize) -> ModInt<P> {
+            self.ensure(n);
+            self.inv_fact[n]
+        }
+
+        pub fn inv(&mut self, n: usize) -> ModInt<P> {
+            assert!(n > 0);
+            self.ensure(n);
+            self.inv_fact[n] * self.fact[n - 1]
+        }
+
+        pub fn binom(&mut self, n: usize, k: usize) -> ModInt<P> {
+            if k > n {
+                return ModInt::default();
+            }
+            self.ensure(n);
+            self.fact[n] * self.inv_fact[k] * self.inv_fact[n - k]
+        }
+
+        pub fn perm(&mut self, n: usize, k: usize) -> ModInt<P> {
+            if k > n {
+                return ModInt::default();
+            }
+            self.ensure(n);
+            self.fact[n] * self.inv_fact[n - k]
+        }
+
+        // (k_1 + ... + k_m)! / (k_1! ... k_m!)
+        pub fn multinomial(&mut self, ks: &[usize]) -> ModInt<P> {
+            let n = ks.iter().sum();
+            self.ensure(n);
+            ks.iter()
+                .fold(self.fact[n], |acc, &k| acc * self.inv_fact[k])
+        }
+
+        pub fn catalan(&mut self, n: usize) -> ModInt<P> {
+            self.ensure((2 * n).max(n + 1));
+            self.fact[2 * n] * self.inv_fact[n + 1] * self.inv_fact[n]
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::combinatorics::*;
+    use crate::math::modint::modint::{mint998244353, ModInt};
+
+    #[test]
+    fn test_factorials() {
+        let mut f = Factorials::<998244353>::new(10);
+        assert_eq!(f.fact(5), 120.into());
+        assert_eq!(f.inv_fact(5) * f.fact(5), 1.into());
+        assert_eq!(f.inv(7) * mint998244353::new(7), 1.into());
+        assert_eq!(f.binom(5, 2), 10.into());
+        assert_eq!(f.binom(2, 5), 0.into());
+        assert_eq!(f.perm(5, 2), 20.into());
+        assert_eq!(f.multinomial(&[1, 2, 3]), 60.into());
+        let catalan = [1, 1, 2, 5, 14, 42, 132, 429];
+        for (n, &c) in catalan.iter().enumerate() {
+            assert_eq!(f.catalan(n), c.into());
+        }
+
+        let mut f = Factorials::<998244353>::default();
+        let mut row = vec![mint998244353::new(1)];
+        for n in 1..=100 {
+            let mut next = vec![mint998244353::new(1); n + 1];
+            for k in 1..n {
+                next[k] = row[k - 1] + row[k];
+            }
+            row = next;
+            for (k, &x) in row.iter().enumerate() {
+                assert_eq!(f.binom(n, k), x);
+            }
+        }
+
+        let mut f = Factorials::<7>::default();
+        assert_eq!(f.fact(6), ModInt::<7>::new(6));
+        assert_eq!(f.binom(6, 3), ModInt::<7>::new(20));
+    }
+}
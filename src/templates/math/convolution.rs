@@ -0,0 +1,259 @@
+pub mod convolution {
+    use crate::math::modint::modint::{qpow, ModInt};
+    use crate::math::number_theory::number_theory;
+
+    pub fn primitive_root(p: i32) -> i32 {
+        match p {
+            998244353 | 167772161 | 469762049 | 7340033 => 3,
+            754974721 => 11,
+            _ => number_theory::primitive_root(p as u64) as i32,
+        }
+    }
+
+    fn bit_reverse<T>(a: &mut [T]) {
+        let n = a.len();
+        let mut j = 0;
+        for i in 1..n {
+            let mut bit = n >> 1;
+            while j & bit != 0 {
+                j ^= bit;
+                bit >>= 1;
+            }
+            j |= bit;
+            if i < j {
+                a.swap(i, j);
+            }
+        }
+    }
+
+    fn transform<const P: i32>(a: &mut [ModInt<P>], invert: bool) {
+        let n = a.len();
+        assert!(n.is_power_of_two());
+        assert!((P - 1) as usize % n == 0);
+        bit_reverse(a);
+        let g = ModInt::<P>::new(primitive_root(P));
+        let g = if invert { g.inv() } else { g };
+        let mut len = 2;
+        while len <= n {
+            let w = ModInt::<P>::new(qpow(g.into(), (P - 1) / len as i32, P));
+            let mut ws = Vec::with_capacity(len / 2);
+            ws.push(ModInt::<P>::new(1));
+            for k in 1..len / 2 {
+                ws.push(ws[k - 1] * w);
+            }
+            for chunk in a.chunks_mut(len) {
+                let (lo, hi) = chunk.split_at_mut(len / 2);
+                for k in 0..len / 2 {
+                    let u = lo[k];
+                    let v = hi[k] * ws[k];
+                    lo[k] = u + v;
+                    hi[k] = u - v;
+                }
+            }
+            len <<= 1;
+        }
+    }
+
+    pub fn ntt<const P: i32>(a: &mut [ModInt<P>]) {
+        transform(a, false);
+    }
+
+    pub fn intt<const P: i32>(a: &mut [ModInt<P>]) {
+        transform(a, true);
+        let inv_n = ModInt::<P>::new(a.len() as i64).inv();
+        for x in a.iter_mut() {
+            *x *= inv_n;
+        }
+    }
+
+    pub fn convolution_naive<const P: i32>(a: &[ModInt<P>], b: &[ModInt<P>]) -> Vec<ModInt<P>> {
+        if a.is_empty() || b.is_empty() {
+            return vec![];
+        }
+        let mut c = vec![ModInt::<P>::default(); a.len() + b.len() - 1];
+        for (i, &x) in a.iter().enumerate() {
+            for (j, &y) in b.iter().enumerate() {
+                c[i + j] += x * y;
+            }
+        }
+        c
+    }
+
+    pub fn convolution<const P: i32>(a: &[ModInt<P>], b: &[ModInt<P>]) -> Vec<ModInt<P>> {
+        if a.is_empty() || b.is_empty() {
+            return vec![];
+        }
+        if a.len().min(b.len()) <= 60 {
+            return convolution_naive(a, b);
+        }
+        let m = a.len() + b.len() - 1;
+        let n = m.next_power_of_two();
+        let mut fa = a.to_vec();
+        let mut fb = b.to_vec();
+        fa.resize(n, ModInt::default());
+        fb.resize(n, ModInt::default());
+        ntt(&mut fa);
+        ntt(&mut fb);
+        for (x, &y) in fa.iter_mut().zip(fb.iter()) {
+            *x *= y;
+        }
+        intt(&mut fa);
+        fa.truncate(m);
+        fa
+    }
+
+    const M1: i32 = 167772161;
+    const M2: i32 = 469762049;
+    const M3: i32 = 754974721;
+
+    fn reduce<const P: i32>(a: &[i128]) -> Vec<ModInt<P>> {
+        a.iter().map(|&x| ModInt::new(x)).collect()
+    }
+
+    // coefficients of the product reduced into [0, M1 * M2 * M3)
+    fn convolution_crt(a: &[i128], b: &[i128]) -> Vec<u128> {
+        if a.is_empty() || b.is_empty() {
+            return vec![];
+        }
+        let c1 = convolution(&reduce::<M1>(a), &reduce::<M1>(b));
+        let c2 = convolution(&reduce::<M2>(a), &reduce::<M2>(b));
+        let c3 = convolution(&reduce::<M3>(a), &reduce::<M3>(b));
+        let m1 = M1 as u128;
+        let m1m2 = M1 as u128 * M2 as u128;
+        let inv_m1_mod_m2 = ModInt::<M2>::new(M1).inv();
+        let inv_m1m2_mod_m3 = (ModInt::<M3>::new(M1) * ModInt::<M3>::new(M2)).inv();
+        (0..c1.len())
+            .map(|i| {
+                let x1: i64 = c1[i].into();
+                let t2 = (c2[i] - ModInt::<M2>::new(x1)) * inv_m1_mod_m2;
+                let x2: i64 = t2.into();
+                let t3 = (c3[i] - ModInt::<M3>::new(x1) - ModInt::<M3>::new(x2) * ModInt::new(M1))
+                    * inv_m1m2_mod_m3;
+                let x3: i64 = t3.into();
+                x1 as u128 + x2 as u128 * m1 + x3 as u128 * m1m2
+            })
+            .collect()
+    }
+
+    pub fn convolution_arbitrary<const P: i32>(a: &[ModInt<P>], b: &[ModInt<P>]) -> Vec<ModInt<P>> {
+        if a.len().min(b.len()) <= 60 {
+            return convolution_naive(a, b);
+        }
+        let widen =
+            |v: &[ModInt<P>]| -> Vec<i128> { v.iter().map(|&x| i64::from(x) as i128).collect() };
+        convolution_crt(&widen(a), &widen(b))
+            .into_iter()
+            .map(|x| ModInt::new((x % P as u128) as i64))
+            .collect()
+    }
+
+    // exact as long as every coefficient of the product fits in u64
+    pub fn convolution_u64(a: &[u64], b: &[u64]) -> Vec<u64> {
+        let widen = |v: &[u64]| -> Vec<i128> { v.iter().map(|&x| x as i128).collect() };
+        convolution_crt(&widen(a), &widen(b))
+            .into_iter()
+            .map(|x| x as u64)
+            .collect()
+    }
+
+    // exact as long as every coefficient of the product has absolute value below M1 * M2 * M3 / 2
+    pub fn convolution_i128(a: &[i64], b: &[i64]) -> Vec<i128> {
+        let m = M1 as i128 * M2 as i128 * M3 as i128;
+        let widen = |v: &[i64]| -> Vec<i128> { v.iter().map(|&x| x as i128).collect() };
+        convolution_crt(&widen(a), &widen(b))
+            .into_iter()
+            .map(|x| {
+                if x as i128 > m / 2 {
+                    x as i128 - m
+                } else {
+                    x as i128
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::convolution::*;
+    use crate::math::modint::modint::{mint998244353, ModInt};
+    use crate::test_util::test_util::XorShift;
+
+    #[test]
+    fn test_primitive_root() {
+        assert_eq!(primitive_root(998244353), 3);
+        assert_eq!(primitive_root(7), 3);
+        assert_eq!(primitive_root(65537), 3);
+        assert_eq!(primitive_root(1000000007), 5);
+    }
+
+    #[test]
+    fn test_convolution_arbitrary() {
+        use crate::math::modint::modint::mint1000000007;
+        let mut rng = XorShift::default();
+        for (n, m) in [(3, 5), (61, 70), (200, 513)] {
+            let a: Vec<mint1000000007> =
+                (0..n).map(|_| ModInt::new(rng.next_u64() as i64)).collect();
+            let b: Vec<mint1000000007> =
+                (0..m).map(|_| ModInt::new(rng.next_u64() as i64)).collect();
+            assert_eq!(convolution_arbitrary(&a, &b), convolution_naive(&a, &b));
+
+            let a: Vec<u64> = (0..n).map(|_| rng.below(1 << 28)).collect();
+            let b: Vec<u64> = (0..m).map(|_| rng.below(1 << 28)).collect();
+            let mut c = vec![0_u64; n + m - 1];
+            for i in 0..n {
+                for j in 0..m {
+                    c[i + j] += a[i] * b[j];
+                }
+            }
+            assert_eq!(convolution_u64(&a, &b), c);
+
+            let a: Vec<i64> = (0..n).map(|_| rng.next_u64() as i64 >> 28).collect();
+            let b: Vec<i64> = (0..m).map(|_| rng.next_u64() as i64 >> 28).collect();
+            let mut c = vec![0_i128; n + m - 1];
+            for i in 0..n {
+                for j in 0..m {
+                    c[i + j] += a[i] as i128 * b[j] as i128;
+                }
+            }
+            assert_eq!(convolution_i128(&a, &b), c);
+        }
+    }
+
+    #[test]
+    fn test_ntt() {
+        let a: Vec<mint998244353> = (1..=8).map(|x: i32| x.into()).collect();
+        let mut b = a.clone();
+        ntt(&mut b);
+        intt(&mut b);
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_convolution() {
+        let a: Vec<mint998244353> = vec![1.into(), 2.into(), 3.into(), 4.into()];
+        let b: Vec<mint998244353> = vec![5.into(), 6.into(), 7.into(), 8.into(), 9.into()];
+        let c: Vec<mint998244353> = [5, 16, 34, 60, 70, 70, 59, 36]
+            .iter()
+            .map(|&x: &i32| x.into())
+            .collect();
+        assert_eq!(convolution(&a, &b), c);
+        assert!(convolution(&a, &[]).is_empty());
+
+        let mut rng = XorShift::default();
+        for (n, m) in [(1, 1), (61, 61), (100, 300), (513, 1000)] {
+            let a: Vec<mint998244353> =
+                (0..n).map(|_| ModInt::new(rng.next_u64() as i64)).collect();
+            let b: Vec<mint998244353> =
+                (0..m).map(|_| ModInt::new(rng.next_u64() as i64)).collect();
+            assert_eq!(convolution(&a, &b), convolution_naive(&a, &b));
+        }
+        let a: Vec<ModInt<469762049>> = (0..200)
+            .map(|_| ModInt::new(rng.next_u64() as i64))
+            .collect();
+        let b: Vec<ModInt<469762049>> = (0..100)
+            .map(|_| ModInt::new(rng.next_u64() as i64))
+            .collect();
+        assert_eq!(convolution(&a, &b), convolution_naive(&a, &b));
+    }
+}
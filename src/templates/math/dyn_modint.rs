@@ -0,0 +1,267 @@
+pub mod dyn_modint {
+    use crate::math::algebra::algebra::{Field, One, Zero};
+    use std::cell::Cell;
+
+    #[derive(Copy, Clone, Debug)]
+    pub struct Barrett {
+        m: u32,
+        im: u64,
+    }
+    impl Barrett {
+        pub const fn new(m: u32) -> Self {
+            assert!(m >= 1 && m < 1 << 31);
+            Barrett {
+                m,
+                im: (u64::MAX / m as u64).wrapping_add(1),
+            }
+        }
+        pub fn modulus(&self) -> u32 {
+            self.m
+        }
+        // a * b % m for a, b < m
+        pub fn mul(&self, a: u32, b: u32) -> u32 {
+            let z = a as u64 * b as u64;
+            let x = ((z as u128 * self.im as u128) >> 64) as u64;
+            let v = z.wrapping_sub(x.wrapping_mul(self.m as u64)) as u32;
+            if self.m <= v {
+                v.wrapping_add(self.m)
+            } else {
+                v
+            }
+        }
+    }
+
+    thread_local! {
+        static BARRETT: Cell<Barrett> = const { Cell::new(Barrett::new(998244353)) };
+    }
+
+    fn barrett() -> Barrett {
+        BARRETT.with(|b| b.get())
+    }
+
+    #[derive(Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Debug, Default, Hash)]
+    pub struct DynModInt(u32);
+    impl DynModInt {
+        pub fn set_modulus(m: u32) {
+            BARRETT.with(|b| b.set(Barrett::new(m)));
+        }
+        pub fn modulus() -> u32 {
+            barrett().modulus()
+        }
+        pub fn new<T: Into<i64>>(value: T) -> Self {
+            DynModInt(value.into().rem_euclid(Self::modulus() as i64) as u32)
+        }
+        pub fn raw(value: u32) -> Self {
+            DynModInt(value)
+        }
+        pub fn val(self) -> u32 {
+            self.0
+        }
+        // works for any modulus coprime with self
+        pub fn inv(self) -> Self {
+            let (mut a, mut b) = (self.0 as i64, Self::modulus() as i64);
+            let (mut x, mut y) = (1_i64, 0_i64);
+            while b != 0 {
+                let t = a / b;
+                (a, b) = (b, a - t * b);
+                (x, y) = (y, x - t * y);
+            }
+            assert!(a == 1, "{} is not invertible", self.0);
+            Self::new(x)
+        }
+        pub fn pow(self, mut y: u64) -> Self {
+            let b = barrett();
+            let mut x = self.0;
+            let mut ans = 1 % b.modulus();
+            while y != 0 {
+                if (y & 1) == 1 {
+                    ans = b.mul(ans, x);
+                }
+                x = b.mul(x, x);
+                y >>= 1;
+            }
+            DynModInt(ans)
+        }
+    }
+    impl std::fmt::Display for DynModInt {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{}", self.0)
+        }
+    }
+    impl std::ops::Add for DynModInt {
+        type Output = DynModInt;
+        fn add(self, rhs: Self) -> Self::Output {
+            let m = Self::modulus();
+            let result = self.0 + rhs.0;
+            DynModInt(if result >= m { result - m } else { result })
+        }
+    }
+    impl std::ops::AddAssign for DynModInt {
+        fn add_assign(&mut self, rhs: Self) {
+            *self = *self + rhs;
+        }
+    }
+    impl std::ops::Sub for DynModInt {
+        type Output = DynModInt;
+        fn sub(self, rhs: Self) -> Self::Output {
+            let result = self.0.wrapping_sub(rhs.0);
+            DynModInt(if self.0 < rhs.0 {
+                result.wrapping_add(Self::modulus())
+            } else {
+                result
+            })
+        }
+    }
+    impl std::ops::SubAssign for DynModInt {
+        fn sub_assign(&mut self, rhs: Self) {
+            *self = *self - rhs;
+        }
+    }
+    impl std::ops::Neg for DynModInt {
+        type Output = DynModInt;
+        fn neg(self) -> Self::Output {
+            DynModInt(if self.0 == 0 {
+                0
+            } else {
+                Self::modulus() - self.0
+            })
+        }
+    }
+    impl std::ops::Mul for DynModInt {
+        type Output = DynModInt;
+        fn mul(self, rhs: Self) -> Self::Output {
+            DynModInt(barrett().mul(self.0, rhs.0))
+        }
+    }
+    impl std::ops::MulAssign for DynModInt {
+        fn mul_assign(&mut self, rhs: Self) {
+            *self = *self * rhs;
+        }
+    }
+    impl std::ops::Div for DynModInt {
+        type Output = DynModInt;
+        #[allow(clippy::suspicious_arithmetic_impl)]
+        fn div(self, rhs: Self) -> Self::Output {
+            self * rhs.inv()
+        }
+    }
+    impl std::ops::DivAssign for DynModInt {
+        fn div_assign(&mut self, rhs: Self) {
+            *self = *self / rhs;
+        }
+    }
+    impl From<DynModInt> for u32 {
+        fn from(value: DynModInt) -> Self {
+            value.0
+        }
+    }
+    impl From<DynModInt> for i64 {
+        fn from(value: DynModInt) -> Self {
+            value.0 as i64
+        }
+    }
+    impl From<i32> for DynModInt {
+        fn from(value: i32) -> Self {
+            DynModInt::new(value)
+        }
+    }
+    impl From<i64> for DynModInt {
+        fn from(value: i64) -> Self {
+            DynModInt::new(value)
+        }
+    }
+    impl From<u32> for DynModInt {
+        fn from(value: u32) -> Self {
+            DynModInt::new(value)
+        }
+    }
+
+    impl Zero for DynModInt {
+        fn zero() -> Self {
+            DynModInt(0)
+        }
+        fn is_zero(&self) -> bool {
+            self.0 == 0
+        }
+    }
+    impl One for DynModInt {
+        fn one() -> Self {
+            DynModInt::new(1)
+        }
+    }
+    impl Field for DynModInt {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::dyn_modint::*;
+    use crate::test_util::test_util::XorShift;
+
+    #[test]
+    fn test_dyn_modint() {
+        type Mint = DynModInt;
+        Mint::set_modulus(31);
+        let mut x: Mint = 10.into();
+        let y: Mint = 12.into();
+        assert_eq!(x + y, 22.into());
+        assert_eq!(x * y, 27.into());
+        assert_eq!(x - y, 29.into());
+        assert_eq!(x.inv(), 28.into());
+        assert_eq!(x / y * y, x);
+        assert_eq!(Mint::new(10).pow(2), 7.into());
+        x += y;
+        assert_eq!(x, 22.into());
+        assert_eq!(Mint::new(1e16 as i64), 10.into());
+        assert_eq!(Mint::new(-1), 30.into());
+        assert_eq!(format!("{}", -Mint::new(1)), "30");
+
+        Mint::set_modulus(12);
+        assert_eq!(Mint::new(5).inv(), 5.into());
+        assert_eq!(Mint::new(7) * Mint::new(7), 1.into());
+    }
+
+    #[test]
+    fn test_barrett() {
+        let mut rng = XorShift::default();
+        for m in [
+            1_u32,
+            2,
+            3,
+            1000000007,
+            998244353,
+            i32::MAX as u32,
+            (1 << 31) - 2,
+        ] {
+            let b = Barrett::new(m);
+            for _ in 0..1000 {
+                let x = rng.below(m as u64) as u32;
+                let y = rng.below(m as u64) as u32;
+                assert_eq!(b.mul(x, y) as u64, x as u64 * y as u64 % m as u64);
+            }
+        }
+        DynModInt::set_modulus(i32::MAX as u32);
+        let x = DynModInt::new(i32::MAX as i64 - 1);
+        assert_eq!(x + x, DynModInt::new(i32::MAX as i64 - 2));
+        assert_eq!(x * x, 1.into());
+    }
+
+    #[test]
+    fn test_dyn_modint_templates() {
+        use crate::data_structure::fenwick::fenwick::Fenwick;
+        use crate::math::matrix::matrix::Matrix;
+        DynModInt::set_modulus(13);
+        let fib = Matrix::from(vec![vec![DynModInt::new(1); 2], vec![1.into(), 0.into()]]);
+        assert_eq!(fib.pow(10)[0][1], 55.into());
+        let m = Matrix::from(vec![
+            vec![2.into(), 3.into()],
+            vec![DynModInt::new(1), 4.into()],
+        ]);
+        assert_eq!(m.determinant(), 5.into());
+        assert_eq!(&m * &m.inverse().unwrap(), Matrix::identity(2));
+        let mut fen = Fenwick::<DynModInt>::new(5);
+        for i in 1..=5 {
+            fen.add(i, DynModInt::new(i as i64 * 7));
+        }
+        assert_eq!(fen.get_range(2, 4), 63.into());
+    }
+}
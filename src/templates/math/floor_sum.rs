@@ -0,0 +1,153 @@
+pub mod floor_sum {
+    use crate::math::modint::modint::ModInt;
+
+    fn floor_sum_unsigned(mut n: i128, mut m: i128, mut a: i128, mut b: i128) -> i128 {
+        let mut ans = 0;
+        loop {
+            if a >= m {
+                ans += n * (n - 1) / 2 * (a / m);
+                a %= m;
+            }
+            if b >= m {
+                ans += n * (b / m);
+                b %= m;
+            }
+            let y_max = a * n + b;
+            if y_max < m {
+                return ans;
+            }
+            (n, b) = (y_max / m, y_max % m);
+            (m, a) = (a, m);
+        }
+    }
+
+    // sum of floor((a * i + b) / m) for i in 0..n
+    pub fn floor_sum(n: i64, m: i64, a: i64, b: i64) -> i64 {
+        assert!(n >= 0 && m >= 1);
+        let (n, m, a, b) = (n as i128, m as i128, a as i128, b as i128);
+        let (a2, b2) = (a.rem_euclid(m), b.rem_euclid(m));
+        let ans = floor_sum_unsigned(n, m, a2, b2)
+            - n * (n - 1) / 2 * ((a2 - a) / m)
+            - n * ((b2 - b) / m);
+        ans as i64
+    }
+
+    // sums of f, i * f and f^2 with f = floor((a * i + b) / c) for i in 0..=n, a, b >= 0
+    fn similar_euclid<const P: i32>(
+        a: i128,
+        b: i128,
+        c: i128,
+        n: i128,
+    ) -> (ModInt<P>, ModInt<P>, ModInt<P>) {
+        let mint = |x: i128| ModInt::<P>::new((x % P as i128) as i64);
+        let (nn, inv2, inv3) = (mint(n), mint(2).inv(), mint(3).inv());
+        let s1 = nn * (nn + mint(1)) * inv2;
+        let s2 = s1 * (mint(2) * nn + mint(1)) * inv3;
+        if a == 0 {
+            let q = mint(b / c);
+            return ((nn + mint(1)) * q, q * s1, (nn + mint(1)) * q * q);
+        }
+        if a >= c || b >= c {
+            let (ac, bc) = (mint(a / c), mint(b / c));
+            let (f, g, h) = similar_euclid::<P>(a % c, b % c, c, n);
+            let two = mint(2);
+            return (
+                f + s1 * ac + (nn + mint(1)) * bc,
+                g + ac * s2 + bc * s1,
+                h + ac * ac * s2
+                    + bc * bc * (nn + mint(1))
+                    + two * ac * bc * s1
+                    + two * bc * f
+                    + two * ac * g,
+            );
+        }
+        let m = (a * n + b) / c;
+        if m == 0 {
+            return (mint(0), mint(0), mint(0));
+        }
+        let (f, g, h) = similar_euclid::<P>(c, c - b - 1, a, m - 1);
+        let mm = mint(m);
+        let f_new = nn * mm - f;
+        let g_new = (mm * nn * (nn + mint(1)) - h - f) * inv2;
+        let h_new = nn * mm * (mm + mint(1)) - mint(2) * g - mint(2) * f - f_new;
+        (f_new, g_new, h_new)
+    }
+
+    // (sum of f, sum of i * f, sum of f^2) with f = floor((a * i + b) / m) for i in 0..n,
+    // P must be a prime greater than 3
+    pub fn floor_sum_general<const P: i32>(
+        n: i64,
+        m: i64,
+        a: i64,
+        b: i64,
+    ) -> (ModInt<P>, ModInt<P>, ModInt<P>) {
+        assert!(n >= 0 && m >= 1);
+        let zero = ModInt::<P>::new(0);
+        if n == 0 {
+            return (zero, zero, zero);
+        }
+        let (a2, b2) = (a.rem_euclid(m), b.rem_euclid(m));
+        let (f, g, h) = similar_euclid::<P>(a2 as i128, b2 as i128, m as i128, n as i128 - 1);
+        // f = f2 - ka * i - kb with f2 the floor for the shifted a2, b2
+        let ka = ModInt::<P>::new((a2 as i128 - a as i128) / m as i128);
+        let kb = ModInt::<P>::new((b2 as i128 - b as i128) / m as i128);
+        let nn = ModInt::<P>::new(n);
+        let s1 = nn * (nn - ModInt::new(1)) / ModInt::new(2);
+        let s2 = s1 * (ModInt::new(2) * nn - ModInt::new(1)) / ModInt::new(3);
+        let two = ModInt::<P>::new(2);
+        (
+            f - ka * s1 - kb * nn,
+            g - ka * s2 - kb * s1,
+            h + ka * ka * s2 + kb * kb * nn - two * ka * g - two * kb * f + two * ka * kb * s1,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::floor_sum::*;
+    use crate::math::modint::modint::{mint998244353, ModInt};
+    use crate::test_util::test_util::XorShift;
+
+    fn floor_div(x: i64, y: i64) -> i64 {
+        x.div_euclid(y)
+    }
+
+    #[test]
+    fn test_floor_sum() {
+        let mut rng = XorShift::default();
+        for _ in 0..2000 {
+            let n = rng.range(0, 49);
+            let m = rng.range(1, 30);
+            let a = rng.range(-100, 100);
+            let b = rng.range(-100, 100);
+            let expected: i64 = (0..n).map(|i| floor_div(a * i + b, m)).sum();
+            assert_eq!(floor_sum(n, m, a, b), expected);
+        }
+        assert_eq!(
+            floor_sum(1_000_000_000, 1_000_000_000, 999_999_999, 999_999_999),
+            499_999_999_500_000_000
+        );
+    }
+
+    #[test]
+    fn test_floor_sum_general() {
+        let mut rng = XorShift::default();
+        for _ in 0..2000 {
+            let n = rng.range(0, 49);
+            let m = rng.range(1, 30);
+            let a = rng.range(-100, 100);
+            let b = rng.range(-100, 100);
+            let mut expected = (ModInt::new(0), ModInt::new(0), ModInt::new(0));
+            for i in 0..n {
+                let f = mint998244353::new(floor_div(a * i + b, m));
+                expected.0 += f;
+                expected.1 += ModInt::new(i) * f;
+                expected.2 += f * f;
+            }
+            assert_eq!(floor_sum_general(n, m, a, b), expected);
+        }
+        let (f, _, _) = floor_sum_general::<998244353>(1_000_000_000, 7, 3, 5);
+        assert_eq!(f, ModInt::new(floor_sum(1_000_000_000, 7, 3, 5)));
+    }
+}
@@ -0,0 +1,316 @@
+pub mod formal_power_series {
+    use crate::math::convolution::convolution::convolution;
+    use crate::math::modint::modint::{qpow, ModInt};
+    use crate::math::number_theory::number_theory::sqrt_mod;
+
+    #[derive(Clone, PartialEq, Eq, Debug, Default)]
+    pub struct FormalPowerSeries<const P: i32>(pub Vec<ModInt<P>>);
+
+    impl<const P: i32> FormalPowerSeries<P> {
+        pub fn new(coef: Vec<ModInt<P>>) -> Self {
+            FormalPowerSeries(coef)
+        }
+
+        pub fn len(&self) -> usize {
+            self.0.len()
+        }
+
+        pub fn is_empty(&self) -> bool {
+            self.0.is_empty()
+        }
+
+        // first n coefficients, padded with zeros
+        pub fn pre(&self, n: usize) -> Self {
+            let mut coef = self.0[..n.min(self.len())].to_vec();
+            coef.resize(n, ModInt::default());
+            FormalPowerSeries(coef)
+        }
+
+        fn trim(mut self) -> Self {
+            while self.0.last() == Some(&ModInt::default()) {
+                self.0.pop();
+            }
+            self
+        }
+
+        pub fn derivative(&self) -> Self {
+            FormalPowerSeries(
+                (1..self.len())
+                    .map(|i| self.0[i] * ModInt::new(i as i64))
+                    .collect(),
+            )
+        }
+
+        pub fn integral(&self) -> Self {
+            let n = self.len();
+            let mut inv = vec![ModInt::<P>::new(1); n + 1];
+            for i in 2..=n {
+                inv[i] = -inv[P as usize % i] * ModInt::new((P as usize / i) as i64);
+            }
+            let mut coef = vec![ModInt::default(); n + 1];
+            for i in 0..n {
+                coef[i + 1] = self.0[i] * inv[i + 1];
+            }
+            FormalPowerSeries(coef)
+        }
+
+        pub fn inv(&self, n: usize) -> Self {
+            assert!(!self.is_empty() && self.0[0] != ModInt::default());
+            let mut g = FormalPowerSeries(vec![self.0[0].inv()]);
+            let mut len = 1;
+            while len < n {
+                len <<= 1;
+                let h = (self.pre(len) * g.clone()).pre(len);
+                g = (g.clone() * (FormalPowerSeries(vec![ModInt::new(2)]) - h)).pre(len);
+            }
+            g.pre(n)
+        }
+
+        pub fn log(&self, n: usize) -> Self {
+            assert!(!self.is_empty() && self.0[0] == ModInt::new(1));
+            if n == 0 {
+                return FormalPowerSeries(vec![]);
+            }
+            (self.pre(n).derivative() * self.inv(n))
+                .pre(n - 1)
+                .integral()
+        }
+
+        pub fn exp(&self, n: usize) -> Self {
+            assert!(self.is_empty() || self.0[0] == ModInt::default());
+            let one = FormalPowerSeries(vec![ModInt::new(1)]);
+            let mut g = one.clone();
+            let mut len = 1;
+            while len < n {
+                len <<= 1;
+                g = (g.clone() * (self.pre(len) - g.log(len) + one.clone())).pre(len);
+            }
+            g.pre(n)
+        }
+
+        pub fn sqrt(&self, n: usize) -> Option<Self> {
+            let k = match self.0.iter().position(|&x| x != ModInt::default()) {
+                Some(k) => k,
+                None => return Some(FormalPowerSeries(vec![ModInt::default(); n])),
+            };
+            if k % 2 == 1 {
+                return None;
+            }
+            if k / 2 >= n {
+                return Some(FormalPowerSeries(vec![ModInt::default(); n]));
+            }
+            let f = FormalPowerSeries(self.0[k..].to_vec());
+            let m = n - k / 2;
+            let mut g = FormalPowerSeries(vec![sqrt_mod(f.0[0])?]);
+            let inv2 = ModInt::<P>::new(2).inv();
+            let mut len = 1;
+            while len < m {
+                len <<= 1;
+                g = (g.clone() + (f.pre(len) * g.inv(len)).pre(len)) * inv2;
+            }
+            let mut coef = vec![ModInt::default(); k / 2];
+            coef.extend(g.pre(m).0);
+            Some(FormalPowerSeries(coef))
+        }
+
+        pub fn pow(&self, k: u64, n: usize) -> Self {
+            if k == 0 {
+                return FormalPowerSeries(vec![ModInt::new(1)]).pre(n);
+            }
+            let i = match self.0.iter().position(|&x| x != ModInt::default()) {
+                Some(i) => i,
+                None => return FormalPowerSeries(vec![ModInt::default(); n]),
+            };
+            if i as u128 * k as u128 >= n as u128 {
+                return FormalPowerSeries(vec![ModInt::default(); n]);
+            }
+            let shift = i * k as usize;
+            let c = self.0[i];
+            let f = FormalPowerSeries(self.0[i..].to_vec()) * c.inv();
+            let ck = ModInt::new(qpow(c.into(), (k % (P as u64 - 1)) as i32, P));
+            let g = (f.log(n - shift) * ModInt::new((k % P as u64) as i64)).exp(n - shift) * ck;
+            let mut coef = vec![ModInt::default(); shift];
+            coef.extend(g.0);
+            FormalPowerSeries(coef)
+        }
+
+        pub fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+            let f = self.clone().trim();
+            let g = rhs.clone().trim();
+            assert!(!g.is_empty());
+            if f.len() < g.len() {
+                return (FormalPowerSeries(vec![]), f);
+            }
+            let k = f.len() - g.len() + 1;
+            let rev = |x: &Self| FormalPowerSeries(x.0.iter().rev().cloned().collect::<Vec<_>>());
+            let mut q = (rev(&f).pre(k) * rev(&g).inv(k)).pre(k);
+            q.0.reverse();
+            let r = (f - q.clone() * g.clone()).pre(g.len() - 1).trim();
+            (q.trim(), r)
+        }
+    }
+
+    impl<const P: i32> std::ops::Add for FormalPowerSeries<P> {
+        type Output = FormalPowerSeries<P>;
+        fn add(mut self, rhs: Self) -> Self::Output {
+            if self.len() < rhs.len() {
+                self.0.resize(rhs.len(), ModInt::default());
+            }
+            for (x, &y) in self.0.iter_mut().zip(rhs.0.iter()) {
+                *x += y;
+            }
+            self
+        }
+    }
+    impl<const P: i32> std::ops::AddAssign for FormalPowerSeries<P> {
+        fn add_assign(&mut self, rhs: Self) {
+            *self = std::mem::take(self) + rhs;
+        }
+    }
+    impl<const P: i32> std::ops::Sub for FormalPowerSeries<P> {
+        type Output = FormalPowerSeries<P>;
+        fn sub(mut self, rhs: Self) -> Self::Output {
+            if self.len() < rhs.len() {
+                self.0.resize(rhs.len(), ModInt::default());
+            }
+            for (x, &y) in self.0.iter_mut().zip(rhs.0.iter()) {
+                *x -= y;
+            }
+            self
+        }
+    }
+    impl<const P: i32> std::ops::SubAssign for FormalPowerSeries<P> {
+        fn sub_assign(&mut self, rhs: Self) {
+            *self = std::mem::take(self) - rhs;
+        }
+    }
+    impl<const P: i32> std::ops::Neg for FormalPowerSeries<P> {
+        type Output = FormalPowerSeries<P>;
+        fn neg(self) -> Self::Output {
+            FormalPowerSeries(self.0.into_iter().map(|x| -x).collect())
+        }
+    }
+    impl<const P: i32> std::ops::Mul for FormalPowerSeries<P> {
+        type Output = FormalPowerSeries<P>;
+        fn mul(self, rhs: Self) -> Self::Output {
+            FormalPowerSeries(convolution(&self.0, &rhs.0))
+        }
+    }
+    impl<const P: i32> std::ops::MulAssign for FormalPowerSeries<P> {
+        fn mul_assign(&mut self, rhs: Self) {
+            *self = std::mem::take(self) * rhs;
+        }
+    }
+    impl<const P: i32> std::ops::Mul<ModInt<P>> for FormalPowerSeries<P> {
+        type Output = FormalPowerSeries<P>;
+        fn mul(self, rhs: ModInt<P>) -> Self::Output {
+            FormalPowerSeries(self.0.into_iter().map(|x| x * rhs).collect())
+        }
+    }
+    impl<const P: i32> std::ops::MulAssign<ModInt<P>> for FormalPowerSeries<P> {
+        fn mul_assign(&mut self, rhs: ModInt<P>) {
+            *self = std::mem::take(self) * rhs;
+        }
+    }
+    impl<const P: i32> std::ops::Div for FormalPowerSeries<P> {
+        type Output = FormalPowerSeries<P>;
+        fn div(self, rhs: Self) -> Self::Output {
+            self.div_rem(&rhs).0
+        }
+    }
+    impl<const P: i32> std::ops::Rem for FormalPowerSeries<P> {
+        type Output = FormalPowerSeries<P>;
+        fn rem(self, rhs: Self) -> Self::Output {
+            self.div_rem(&rhs).1
+        }
+    }
+    impl<const P: i32> From<Vec<ModInt<P>>> for FormalPowerSeries<P> {
+        fn from(value: Vec<ModInt<P>>) -> Self {
+            FormalPowerSeries(value)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::formal_power_series::*;
+    use crate::math::modint::modint::mint998244353;
+    use crate::test_util::test_util::XorShift;
+
+    type Fps = FormalPowerSeries<998244353>;
+
+    fn fps(v: &[i64]) -> Fps {
+        Fps::new(v.iter().map(|&x| mint998244353::from(x)).collect())
+    }
+
+    fn random_fps(n: usize, rng: &mut XorShift) -> Fps {
+        Fps::new(
+            (0..n)
+                .map(|_| mint998244353::new(rng.next_u64() as i64))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_fps_arithmetic() {
+        let f = fps(&[1, 2, 3]);
+        let g = fps(&[4, 5]);
+        assert_eq!(f.clone() + g.clone(), fps(&[5, 7, 3]));
+        assert_eq!(f.clone() - g.clone(), fps(&[-3, -3, 3]));
+        assert_eq!(f.clone() * g.clone(), fps(&[4, 13, 22, 15]));
+        assert_eq!(-g.clone(), fps(&[-4, -5]));
+        assert_eq!(f.derivative(), fps(&[2, 6]));
+        assert_eq!(f.integral().derivative(), f);
+
+        let mut rng = XorShift::default();
+        for (n, m) in [(10, 3), (100, 37), (5, 8)] {
+            let f = random_fps(n, &mut rng);
+            let g = random_fps(m, &mut rng);
+            let (q, r) = f.div_rem(&g);
+            assert!(r.len() < g.len());
+            assert_eq!((q * g + r).pre(n), f);
+        }
+    }
+
+    #[test]
+    fn test_fps_inv_log_exp() {
+        let n = 100;
+        let mut rng = XorShift::default();
+        let mut f = random_fps(n, &mut rng);
+        assert_eq!((f.clone() * f.inv(n)).pre(n), fps(&[1]).pre(n));
+
+        f.0[0] = 1.into();
+        assert_eq!(f.log(n).exp(n), f);
+
+        let e = fps(&[0, 1]).exp(6);
+        let fact_inv: Vec<mint998244353> = [1, 1, 2, 6, 24, 120]
+            .iter()
+            .map(|&x: &i32| mint998244353::from(x).inv())
+            .collect();
+        assert_eq!(e, Fps::new(fact_inv));
+    }
+
+    #[test]
+    fn test_fps_sqrt_pow() {
+        let n = 50;
+        let mut rng = XorShift::default();
+        let g = random_fps(n, &mut rng);
+        let f = (g.clone() * g.clone()).pre(n);
+        let s = f.sqrt(n).unwrap();
+        assert_eq!((s.clone() * s).pre(n), f);
+
+        let shifted = fps(&[0, 0, 4, 4, 1]);
+        let s = shifted.sqrt(5).unwrap();
+        assert_eq!((s.clone() * s).pre(5), shifted);
+        assert!(fps(&[0, 1]).sqrt(3).is_none());
+        assert!(fps(&[3]).sqrt(3).is_none());
+
+        let f = fps(&[0, 2, 1, 5]);
+        let mut expected = fps(&[1]);
+        for k in 0..6 {
+            assert_eq!(f.pow(k, 12), expected.pre(12));
+            expected *= f.clone();
+        }
+        assert_eq!(f.pow(1 << 40, 12), fps(&[0]).pre(12));
+    }
+}
@@ -0,0 +1,229 @@
+pub mod gaussian_elimination {
+    pub use crate::math::algebra::algebra::Field;
+    use crate::math::matrix::matrix::Matrix;
+
+    // reduced row echelon form over the first `limit` columns, returns pivot columns and the
+    // determinant of the leading square block
+    fn eliminate<T: Field>(a: &mut [Vec<T>], limit: usize) -> (Vec<usize>, T) {
+        let n = a.len();
+        let mut det = T::one();
+        let mut pivots = vec![];
+        let mut r = 0;
+        for c in 0..limit {
+            if r == n {
+                break;
+            }
+            let p = (r..n).fold(r, |best, i| {
+                if a[i][c].pivot_score() > a[best][c].pivot_score() {
+                    i
+                } else {
+                    best
+                }
+            });
+            if a[p][c].is_negligible() {
+                det = T::zero();
+                continue;
+            }
+            if p != r {
+                a.swap(p, r);
+                det = -det;
+            }
+            det = det * a[r][c];
+            let inv = T::one() / a[r][c];
+            for x in a[r][c..].iter_mut() {
+                *x = *x * inv;
+            }
+            for i in 0..n {
+                if i == r || a[i][c].is_negligible() {
+                    continue;
+                }
+                let f = a[i][c];
+                for j in c..a[i].len() {
+                    a[i][j] = a[i][j] - f * a[r][j];
+                }
+            }
+            pivots.push(c);
+            r += 1;
+        }
+        if pivots.len() < limit {
+            det = T::zero();
+        }
+        (pivots, det)
+    }
+
+    impl<T: Field> Matrix<T> {
+        fn to_rows(&self) -> Vec<Vec<T>> {
+            (0..self.rows()).map(|i| self[i].clone()).collect()
+        }
+
+        pub fn determinant(&self) -> T {
+            assert_eq!(self.rows(), self.cols());
+            eliminate(&mut self.to_rows(), self.cols()).1
+        }
+
+        pub fn rank(&self) -> usize {
+            eliminate(&mut self.to_rows(), self.cols()).0.len()
+        }
+
+        pub fn inverse(&self) -> Option<Matrix<T>> {
+            assert_eq!(self.rows(), self.cols());
+            let n = self.rows();
+            let mut a = self.to_rows();
+            for (i, row) in a.iter_mut().enumerate() {
+                row.extend((0..n).map(|j| if i == j { T::one() } else { T::zero() }));
+            }
+            if eliminate(&mut a, n).0.len() < n {
+                return None;
+            }
+            Some(Matrix::from(
+                a.into_iter()
+                    .map(|row| row[n..].to_vec())
+                    .collect::<Vec<_>>(),
+            ))
+        }
+
+        // one solution of self * x = b and a basis of the null space, None if inconsistent
+        pub fn solve(&self, b: &[T]) -> Option<(Vec<T>, Vec<Vec<T>>)> {
+            assert_eq!(self.rows(), b.len());
+            let m = self.cols();
+            let mut a = self.to_rows();
+            for (row, &y) in a.iter_mut().zip(b.iter()) {
+                row.push(y);
+            }
+            let (pivots, _) = eliminate(&mut a, m);
+            if a[pivots.len()..].iter().any(|row| !row[m].is_negligible()) {
+                return None;
+            }
+            let mut x = vec![T::zero(); m];
+            let mut is_pivot = vec![false; m];
+            for (i, &c) in pivots.iter().enumerate() {
+                x[c] = a[i][m];
+                is_pivot[c] = true;
+            }
+            let mut basis = vec![];
+            for f in (0..m).filter(|&f| !is_pivot[f]) {
+                let mut v = vec![T::zero(); m];
+                v[f] = T::one();
+                for (i, &c) in pivots.iter().enumerate() {
+                    v[c] = -a[i][f];
+                }
+                basis.push(v);
+            }
+            Some((x, basis))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::gaussian_elimination::*;
+    use crate::math::matrix::matrix::Matrix;
+    use crate::math::modint::modint::mint998244353;
+    use crate::math::ratio::ratio::Ratio;
+    use crate::test_util::test_util::XorShift;
+
+    fn check_solution<T: Field + PartialEq + std::fmt::Debug>(a: &Matrix<T>, b: &[T]) -> bool {
+        match a.solve(b) {
+            Some((x, basis)) => {
+                assert_eq!(a.mul_vec(&x), b);
+                assert_eq!(basis.len(), a.cols() - a.rank());
+                for v in basis.iter() {
+                    assert!(a.mul_vec(v).iter().all(|y| y.is_zero()));
+                }
+                true
+            }
+            None => false,
+        }
+    }
+
+    #[test]
+    fn test_determinant() {
+        let mut rng = XorShift::default();
+        for n in 1..=5 {
+            let a: Vec<Vec<mint998244353>> = (0..n)
+                .map(|_| (0..n).map(|_| rng.range(0, 4).into()).collect())
+                .collect();
+            let mut perm: Vec<usize> = (0..n).collect();
+            let mut expected = mint998244353::new(0);
+            loop {
+                let inversions = (0..n)
+                    .flat_map(|i| (i + 1..n).map(move |j| (i, j)))
+                    .filter(|&(i, j)| perm[i] > perm[j])
+                    .count();
+                let term = (0..n).fold(mint998244353::new(1), |s, i| s * a[i][perm[i]]);
+                expected += if inversions % 2 == 0 { term } else { -term };
+                let Some(i) = (0..n.saturating_sub(1))
+                    .rev()
+                    .find(|&i| perm[i] < perm[i + 1])
+                else {
+                    break;
+                };
+                let j = (i + 1..n).rev().find(|&j| perm[j] > perm[i]).unwrap();
+                perm.swap(i, j);
+                perm[i + 1..].reverse();
+            }
+            assert_eq!(Matrix::from(a).determinant(), expected);
+        }
+        let a = Matrix::<f64>::from(vec![vec![0.0, 2.0], vec![3.0, 4.0]]);
+        assert!((a.determinant() + 6.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_inverse() {
+        let a = Matrix::<Ratio>::from(vec![
+            vec![Ratio::new(2, 1), Ratio::new(1, 1)],
+            vec![Ratio::new(5, 1), Ratio::new(3, 1)],
+        ]);
+        let inv = a.inverse().unwrap();
+        assert_eq!(&a * &inv, Matrix::identity(2));
+        assert_eq!(inv[0][1], Ratio::new(-1, 1));
+
+        let singular = Matrix::<Ratio>::from(vec![
+            vec![Ratio::new(1, 2), Ratio::new(1, 3)],
+            vec![Ratio::new(3, 2), Ratio::new(1, 1)],
+        ]);
+        assert_eq!(singular.inverse(), None);
+        assert_eq!(singular.rank(), 1);
+
+        let mut rng = XorShift::default();
+        let a: Vec<Vec<mint998244353>> = (0..6)
+            .map(|_| {
+                (0..6)
+                    .map(|_| mint998244353::new(rng.next_u64() as i64))
+                    .collect()
+            })
+            .collect();
+        let a = Matrix::from(a);
+        assert_eq!(&a * &a.inverse().unwrap(), Matrix::identity(6));
+    }
+
+    #[test]
+    fn test_solve() {
+        let mut rng = XorShift::default();
+        let mut solvable = 0;
+        for _ in 0..200 {
+            let n = rng.range(1, 5) as usize;
+            let m = rng.range(1, 5) as usize;
+            let a: Vec<Vec<mint998244353>> = (0..n)
+                .map(|_| (0..m).map(|_| rng.range(0, 2).into()).collect())
+                .collect();
+            let b: Vec<mint998244353> = (0..n).map(|_| rng.range(0, 2).into()).collect();
+            if check_solution(&Matrix::from(a), &b) {
+                solvable += 1;
+            }
+        }
+        assert!(solvable > 0);
+
+        let a = Matrix::<Ratio>::from(vec![
+            vec![Ratio::new(1, 1), Ratio::new(2, 1), Ratio::new(3, 1)],
+            vec![Ratio::new(2, 1), Ratio::new(4, 1), Ratio::new(6, 1)],
+        ]);
+        assert!(check_solution(&a, &[Ratio::new(1, 1), Ratio::new(2, 1)]));
+        assert!(!check_solution(&a, &[Ratio::new(1, 1), Ratio::new(3, 1)]));
+
+        let a = Matrix::<f64>::from(vec![vec![1e-12, 1.0], vec![1.0, 1.0]]);
+        let (x, basis) = a.solve(&[1.0, 2.0]).unwrap();
+        assert!(basis.is_empty());
+        assert!((x[0] - 1.0).abs() < 1e-9 && (x[1] - 1.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,313 @@
+pub mod gf2 {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct XorBasis {
+        // basis[i] is zero or has highest set bit i
+        basis: [u64; 64],
+        rank: usize,
+        inserted: usize,
+    }
+
+    impl XorBasis {
+        pub fn new() -> Self {
+            XorBasis {
+                basis: [0; 64],
+                rank: 0,
+                inserted: 0,
+            }
+        }
+
+        pub fn rank(&self) -> usize {
+            self.rank
+        }
+
+        // true if x was independent of the basis
+        pub fn insert(&mut self, mut x: u64) -> bool {
+            self.inserted += 1;
+            for i in (0..64).rev() {
+                if (x >> i) & 1 == 0 {
+                    continue;
+                }
+                if self.basis[i] == 0 {
+                    self.basis[i] = x;
+                    self.rank += 1;
+                    return true;
+                }
+                x ^= self.basis[i];
+            }
+            false
+        }
+
+        pub fn contains(&self, mut x: u64) -> bool {
+            for i in (0..64).rev() {
+                if (x >> i) & 1 == 1 {
+                    x ^= self.basis[i];
+                }
+            }
+            x == 0
+        }
+
+        // max of x ^ y over y in the span
+        pub fn max_xor(&self, x: u64) -> u64 {
+            (0..64).rev().fold(x, |acc, i| acc.max(acc ^ self.basis[i]))
+        }
+
+        // min xor of a non-empty subset of the inserted values
+        pub fn min_xor(&self) -> Option<u64> {
+            if self.inserted == 0 {
+                return None;
+            }
+            if self.inserted > self.rank {
+                return Some(0);
+            }
+            self.reduced().first().copied()
+        }
+
+        // basis vectors with every leading bit cleared from the others, in increasing order
+        fn reduced(&self) -> Vec<u64> {
+            let mut b = self.basis;
+            for i in 0..64 {
+                if b[i] == 0 {
+                    continue;
+                }
+                for j in i + 1..64 {
+                    if (b[j] >> i) & 1 == 1 {
+                        b[j] ^= b[i];
+                    }
+                }
+            }
+            b.into_iter().filter(|&x| x != 0).collect()
+        }
+
+        // k-th (0-indexed) smallest distinct value of the span, which always contains 0
+        pub fn kth_smallest(&self, k: u64) -> Option<u64> {
+            if self.rank < 64 && k >> self.rank != 0 {
+                return None;
+            }
+            Some(
+                self.reduced()
+                    .iter()
+                    .enumerate()
+                    .filter(|&(i, _)| (k >> i) & 1 == 1)
+                    .fold(0, |acc, (_, &x)| acc ^ x),
+            )
+        }
+
+        pub fn merge(&mut self, other: &XorBasis) {
+            let extra = other.inserted - other.rank;
+            for &x in other.basis.iter().filter(|&&x| x != 0) {
+                self.insert(x);
+            }
+            self.inserted += extra;
+        }
+    }
+
+    impl Default for XorBasis {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    pub struct BitSet {
+        n: usize,
+        w: Vec<u64>,
+    }
+
+    impl BitSet {
+        pub fn new(n: usize) -> Self {
+            BitSet {
+                n,
+                w: vec![0; n.div_ceil(64)],
+            }
+        }
+
+        pub fn len(&self) -> usize {
+            self.n
+        }
+
+        pub fn is_empty(&self) -> bool {
+            self.n == 0
+        }
+
+        pub fn get(&self, i: usize) -> bool {
+            (self.w[i >> 6] >> (i & 63)) & 1 == 1
+        }
+
+        pub fn set(&mut self, i: usize, v: bool) {
+            if v {
+                self.w[i >> 6] |= 1 << (i & 63);
+            } else {
+                self.w[i >> 6] &= !(1 << (i & 63));
+            }
+        }
+
+        pub fn count_ones(&self) -> usize {
+            self.w.iter().map(|x| x.count_ones() as usize).sum()
+        }
+
+        // self ^= other on the words holding bits from `from` onwards
+        fn xor_from(&mut self, other: &BitSet, from: usize) {
+            for (x, &y) in self.w[from >> 6..]
+                .iter_mut()
+                .zip(other.w[from >> 6..].iter())
+            {
+                *x ^= y;
+            }
+        }
+    }
+
+    impl std::ops::BitXorAssign<&BitSet> for BitSet {
+        fn bitxor_assign(&mut self, rhs: &BitSet) {
+            self.xor_from(rhs, 0);
+        }
+    }
+
+    // reduced row echelon form over the first `limit` columns, returns pivot columns
+    fn eliminate(a: &mut [BitSet], limit: usize) -> Vec<usize> {
+        let mut pivots = vec![];
+        let mut r = 0;
+        for c in 0..limit {
+            if r == a.len() {
+                break;
+            }
+            let Some(p) = (r..a.len()).find(|&i| a[i].get(c)) else {
+                continue;
+            };
+            a.swap(p, r);
+            let (head, tail) = a.split_at_mut(r);
+            let (row, tail) = tail.split_first_mut().unwrap();
+            for other in head.iter_mut().chain(tail.iter_mut()) {
+                if other.get(c) {
+                    other.xor_from(row, c);
+                }
+            }
+            pivots.push(c);
+            r += 1;
+        }
+        pivots
+    }
+
+    pub fn rank_gf2(a: &[BitSet], m: usize) -> usize {
+        eliminate(&mut a.to_vec(), m).len()
+    }
+
+    // a has rows of m bits; one solution of a * x = b and a basis of the null space
+    pub fn solve_gf2(a: &[BitSet], b: &[bool], m: usize) -> Option<(BitSet, Vec<BitSet>)> {
+        assert_eq!(a.len(), b.len());
+        let mut rows: Vec<BitSet> = a
+            .iter()
+            .zip(b.iter())
+            .map(|(row, &y)| {
+                let mut r = BitSet::new(m + 1);
+                r.w[..row.w.len()].copy_from_slice(&row.w);
+                r.set(m, y);
+                r
+            })
+            .collect();
+        let pivots = eliminate(&mut rows, m);
+        if rows[pivots.len()..].iter().any(|row| row.get(m)) {
+            return None;
+        }
+        let mut x = BitSet::new(m);
+        let mut is_pivot = vec![false; m];
+        for (i, &c) in pivots.iter().enumerate() {
+            x.set(c, rows[i].get(m));
+            is_pivot[c] = true;
+        }
+        let mut basis = vec![];
+        for f in (0..m).filter(|&f| !is_pivot[f]) {
+            let mut v = BitSet::new(m);
+            v.set(f, true);
+            for (i, &c) in pivots.iter().enumerate() {
+                v.set(c, rows[i].get(f));
+            }
+            basis.push(v);
+        }
+        Some((x, basis))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::gf2::*;
+    use crate::test_util::test_util::XorShift;
+
+    #[test]
+    fn test_xor_basis() {
+        let mut rng = XorShift::default();
+        for _ in 0..50 {
+            let values: Vec<u64> = (0..6).map(|_| rng.below(64)).collect();
+            let mut basis = XorBasis::new();
+            let (left, right) = values.split_at(3);
+            let mut other = XorBasis::new();
+            for &x in left {
+                basis.insert(x);
+            }
+            for &x in right {
+                other.insert(x);
+            }
+            basis.merge(&other);
+
+            let mut subsets: Vec<u64> = (1..1_u32 << values.len())
+                .map(|mask| {
+                    (0..values.len())
+                        .filter(|&i| (mask >> i) & 1 == 1)
+                        .fold(0, |acc, i| acc ^ values[i])
+                })
+                .collect();
+            assert_eq!(basis.min_xor(), subsets.iter().min().copied());
+            subsets.push(0);
+            subsets.sort_unstable();
+            subsets.dedup();
+            assert_eq!(subsets.len(), 1 << basis.rank());
+            for (k, &x) in subsets.iter().enumerate() {
+                assert_eq!(basis.kth_smallest(k as u64), Some(x));
+            }
+            assert_eq!(basis.kth_smallest(subsets.len() as u64), None);
+            for x in 0..64 {
+                assert_eq!(basis.contains(x), subsets.binary_search(&x).is_ok());
+                assert_eq!(
+                    basis.max_xor(x),
+                    subsets.iter().map(|&y| x ^ y).max().unwrap()
+                );
+            }
+        }
+        assert_eq!(XorBasis::new().min_xor(), None);
+    }
+
+    #[test]
+    fn test_solve_gf2() {
+        let mut rng = XorShift::default();
+        for _ in 0..100 {
+            let n = rng.range(1, 150) as usize;
+            let m = rng.range(1, 150) as usize;
+            let mut a = vec![BitSet::new(m); n];
+            for row in a.iter_mut() {
+                for j in 0..m {
+                    row.set(j, rng.next_u64() & 3 == 0);
+                }
+            }
+            let mut truth = BitSet::new(m);
+            for j in 0..m {
+                truth.set(j, rng.next_u64() & 1 == 1);
+            }
+            let mul = |x: &BitSet| -> Vec<bool> {
+                a.iter()
+                    .map(|row| (0..m).filter(|&j| row.get(j) && x.get(j)).count() % 2 == 1)
+                    .collect()
+            };
+            let b = mul(&truth);
+            let (x, basis) = solve_gf2(&a, &b, m).unwrap();
+            assert_eq!(mul(&x), b);
+            assert_eq!(basis.len(), m - rank_gf2(&a, m));
+            for v in basis.iter() {
+                assert!(mul(v).iter().all(|&y| !y));
+                assert!(v.count_ones() > 0);
+            }
+        }
+        let mut a = vec![BitSet::new(2); 2];
+        a[0].set(0, true);
+        a[1].set(0, true);
+        assert!(solve_gf2(&a, &[true, false], 2).is_none());
+    }
+}
@@ -0,0 +1,188 @@
+pub mod interpolation {
+    use crate::math::combinatorics::combinatorics::Factorials;
+    use crate::math::formal_power_series::formal_power_series::FormalPowerSeries;
+    use crate::math::modint::modint::ModInt;
+
+    pub fn evaluate<const P: i32>(f: &[ModInt<P>], x: ModInt<P>) -> ModInt<P> {
+        f.iter().rev().fold(ModInt::new(0), |acc, &c| acc * x + c)
+    }
+
+    // f(x) for the polynomial of degree < n with f(i) = ys[i], i = 0..n, in O(n)
+    pub fn interpolate_at<const P: i32>(ys: &[ModInt<P>], x: ModInt<P>) -> ModInt<P> {
+        let n = ys.len();
+        if n == 0 {
+            return ModInt::new(0);
+        }
+        let mut f = Factorials::<P>::new(n);
+        let mut pre = vec![ModInt::<P>::new(1); n + 1];
+        let mut suf = vec![ModInt::<P>::new(1); n + 1];
+        for i in 0..n {
+            pre[i + 1] = pre[i] * (x - ModInt::new(i as i64));
+        }
+        for i in (0..n).rev() {
+            suf[i] = suf[i + 1] * (x - ModInt::new(i as i64));
+        }
+        (0..n).fold(ModInt::new(0), |acc, i| {
+            let term = ys[i] * pre[i] * suf[i + 1] * f.inv_fact(i) * f.inv_fact(n - 1 - i);
+            if (n - 1 - i) & 1 == 1 {
+                acc - term
+            } else {
+                acc + term
+            }
+        })
+    }
+
+    // coefficients of the polynomial of degree < n through (xs[i], ys[i]) in O(n^2)
+    pub fn lagrange_interpolate<const P: i32>(
+        xs: &[ModInt<P>],
+        ys: &[ModInt<P>],
+    ) -> Vec<ModInt<P>> {
+        assert_eq!(xs.len(), ys.len());
+        let n = xs.len();
+        let zero = ModInt::<P>::new(0);
+        // m = prod (x - xs[i])
+        let mut m = vec![zero; n + 1];
+        m[0] = ModInt::new(1);
+        for (k, &xi) in xs.iter().enumerate() {
+            for j in (0..=k).rev() {
+                let t = m[j];
+                m[j + 1] += t;
+                m[j] *= -xi;
+            }
+        }
+        let mut result = vec![zero; n];
+        for i in 0..n {
+            // q = m / (x - xs[i]) by synthetic division
+            let mut q = vec![zero; n];
+            let mut carry = zero;
+            for j in (0..n).rev() {
+                carry = m[j + 1] + carry * xs[i];
+                q[j] = carry;
+            }
+            let coef = ys[i] / evaluate(&q, xs[i]);
+            for (r, &c) in result.iter_mut().zip(q.iter()) {
+                *r += c * coef;
+            }
+        }
+        result
+    }
+
+    pub struct SubproductTree<const P: i32> {
+        n: usize,
+        size: usize,
+        tree: Vec<FormalPowerSeries<P>>,
+    }
+
+    impl<const P: i32> SubproductTree<P> {
+        pub fn new(xs: &[ModInt<P>]) -> Self {
+            let n = xs.len();
+            let size = n.next_power_of_two();
+            let one = FormalPowerSeries::new(vec![ModInt::new(1)]);
+            let mut tree = vec![one; 2 * size];
+            for (i, &x) in xs.iter().enumerate() {
+                tree[size + i] = FormalPowerSeries::new(vec![-x, ModInt::new(1)]);
+            }
+            for i in (1..size).rev() {
+                tree[i] = tree[2 * i].clone() * tree[2 * i + 1].clone();
+            }
+            SubproductTree { n, size, tree }
+        }
+
+        // f(xs[i]) for every i in O(n log^2 n)
+        pub fn evaluate(&self, f: &[ModInt<P>]) -> Vec<ModInt<P>> {
+            let mut rem = vec![FormalPowerSeries::new(vec![]); 2 * self.size];
+            rem[1] = FormalPowerSeries::new(f.to_vec()) % self.tree[1].clone();
+            for i in 2..self.size + self.n {
+                rem[i] = rem[i / 2].clone() % self.tree[i].clone();
+            }
+            (0..self.n)
+                .map(|i| rem[self.size + i].0.first().copied().unwrap_or_default())
+                .collect()
+        }
+
+        // coefficients of the polynomial of degree < n through (xs[i], ys[i]) in O(n log^2 n)
+        pub fn interpolate(&self, ys: &[ModInt<P>]) -> Vec<ModInt<P>> {
+            assert_eq!(self.n, ys.len());
+            let weights = self.evaluate(&self.tree[1].derivative().0);
+            let mut node = vec![FormalPowerSeries::new(vec![]); 2 * self.size];
+            for i in 0..self.n {
+                node[self.size + i] = FormalPowerSeries::new(vec![ys[i] / weights[i]]);
+            }
+            for i in (1..self.size).rev() {
+                node[i] = node[2 * i].clone() * self.tree[2 * i + 1].clone()
+                    + node[2 * i + 1].clone() * self.tree[2 * i].clone();
+            }
+            let mut f = std::mem::take(&mut node[1]).0;
+            f.resize(self.n, ModInt::new(0));
+            f
+        }
+    }
+
+    pub fn multipoint_evaluation<const P: i32>(
+        f: &[ModInt<P>],
+        xs: &[ModInt<P>],
+    ) -> Vec<ModInt<P>> {
+        SubproductTree::new(xs).evaluate(f)
+    }
+
+    pub fn fast_interpolate<const P: i32>(xs: &[ModInt<P>], ys: &[ModInt<P>]) -> Vec<ModInt<P>> {
+        SubproductTree::new(xs).interpolate(ys)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::interpolation::*;
+    use crate::math::modint::modint::{mint1000000007, mint998244353, ModInt};
+    use crate::test_util::test_util::XorShift;
+
+    fn random(n: usize, rng: &mut XorShift) -> Vec<mint998244353> {
+        (0..n).map(|_| ModInt::new(rng.next_u64() as i64)).collect()
+    }
+
+    #[test]
+    fn test_interpolate_at() {
+        // sum of squares 0^2 + ... + (x - 1)^2 sampled at x = 0..4
+        let ys: Vec<mint1000000007> = [0, 0, 1, 5, 14].iter().map(|&y: &i32| y.into()).collect();
+        let x = 1_000_000_i64;
+        let expected =
+            mint1000000007::new((x - 1) * x % 1_000_000_007 * (2 * x - 1)) / mint1000000007::new(6);
+        assert_eq!(interpolate_at(&ys, x.into()), expected);
+        assert_eq!(interpolate_at(&ys, 3.into()), 5.into());
+
+        let mut rng = XorShift::default();
+        let f = random(10, &mut rng);
+        let ys: Vec<mint998244353> = (0..10).map(|i: i32| evaluate(&f, i.into())).collect();
+        for x in random(5, &mut rng) {
+            assert_eq!(interpolate_at(&ys, x), evaluate(&f, x));
+        }
+    }
+
+    #[test]
+    fn test_lagrange_interpolate() {
+        let mut rng = XorShift::default();
+        for n in [1, 2, 7, 20] {
+            let f = random(n, &mut rng);
+            let xs = random(n, &mut rng);
+            let ys: Vec<mint998244353> = xs.iter().map(|&x| evaluate(&f, x)).collect();
+            assert_eq!(lagrange_interpolate(&xs, &ys), f);
+        }
+    }
+
+    #[test]
+    fn test_subproduct_tree() {
+        let mut rng = XorShift::default();
+        for (n, m) in [(1, 1), (5, 3), (100, 80), (77, 200)] {
+            let f = random(n, &mut rng);
+            let xs = random(m, &mut rng);
+            let expected: Vec<mint998244353> = xs.iter().map(|&x| evaluate(&f, x)).collect();
+            assert_eq!(multipoint_evaluation(&f, &xs), expected);
+        }
+        for n in [1, 2, 3, 64, 150] {
+            let f = random(n, &mut rng);
+            let xs = random(n, &mut rng);
+            let ys: Vec<mint998244353> = xs.iter().map(|&x| evaluate(&f, x)).collect();
+            assert_eq!(fast_interpolate(&xs, &ys), f);
+        }
+    }
+}
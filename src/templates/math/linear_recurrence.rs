@@ -0,0 +1,156 @@
+pub mod linear_recurrence {
+    use crate::math::convolution::convolution::{convolution, convolution_arbitrary};
+    use crate::math::modint::modint::ModInt;
+
+    fn multiply<const P: i32>(a: &[ModInt<P>], b: &[ModInt<P>]) -> Vec<ModInt<P>> {
+        let n = (a.len() + b.len()).next_power_of_two();
+        if (P - 1) as usize % n == 0 {
+            convolution(a, b)
+        } else {
+            convolution_arbitrary(a, b)
+        }
+    }
+
+    // shortest c with s[i] = c[0] * s[i - 1] + ... + c[d - 1] * s[i - d], P must be prime
+    pub fn berlekamp_massey<const P: i32>(s: &[ModInt<P>]) -> Vec<ModInt<P>> {
+        let n = s.len();
+        let zero = ModInt::<P>::new(0);
+        // connection polynomials, c[0] = b[0] = 1
+        let mut c = vec![zero; n + 1];
+        let mut b = vec![zero; n + 1];
+        c[0] = ModInt::new(1);
+        b[0] = ModInt::new(1);
+        let (mut len, mut m) = (0, 0);
+        let mut last_d = ModInt::<P>::new(1);
+        for i in 0..n {
+            m += 1;
+            let d = (1..=len).fold(s[i], |acc, j| acc + c[j] * s[i - j]);
+            if d == zero {
+                continue;
+            }
+            let t = c.clone();
+            let coef = d / last_d;
+            for j in m..=n {
+                c[j] -= coef * b[j - m];
+            }
+            if 2 * len > i {
+                continue;
+            }
+            len = i + 1 - len;
+            b = t;
+            last_d = d;
+            m = 0;
+        }
+        c[1..=len].iter().map(|&x| -x).collect()
+    }
+
+    // k-th term of s[i] = c[0] * s[i - 1] + ... + c[d - 1] * s[i - d] with s[0..d] = init
+    pub fn kth_term<const P: i32>(
+        coeffs: &[ModInt<P>],
+        init: &[ModInt<P>],
+        mut k: u64,
+    ) -> ModInt<P> {
+        let d = coeffs.len();
+        assert!(init.len() >= d);
+        if (k as u128) < init.len() as u128 {
+            return init[k as usize];
+        }
+        if d == 0 {
+            return ModInt::new(0);
+        }
+        let mut q = vec![ModInt::<P>::new(1)];
+        q.extend(coeffs.iter().map(|&x| -x));
+        let mut p = multiply(&init[..d], &q);
+        p.truncate(d);
+        while k > 0 {
+            let q_neg: Vec<ModInt<P>> = q
+                .iter()
+                .enumerate()
+                .map(|(i, &x)| if i & 1 == 1 { -x } else { x })
+                .collect();
+            let u = multiply(&p, &q_neg);
+            let v = multiply(&q, &q_neg);
+            p = u.into_iter().skip((k & 1) as usize).step_by(2).collect();
+            q = v.into_iter().step_by(2).collect();
+            k >>= 1;
+        }
+        p.first().copied().unwrap_or_default() / q[0]
+    }
+
+    // k-th term of the sequence whose prefix s determines its minimal recurrence
+    pub fn kth_term_of_sequence<const P: i32>(s: &[ModInt<P>], k: u64) -> ModInt<P> {
+        let c = berlekamp_massey(s);
+        kth_term(&c, s, k)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::linear_recurrence::*;
+    use crate::math::modint::modint::{mint1000000007, mint998244353, ModInt};
+    use crate::test_util::test_util::XorShift;
+
+    #[test]
+    fn test_berlekamp_massey() {
+        let fib: Vec<mint998244353> = (0..20)
+            .scan((0, 1), |st, _| {
+                let x = st.0;
+                *st = (st.1, st.0 + st.1);
+                Some(mint998244353::new(x as i64))
+            })
+            .collect();
+        assert_eq!(berlekamp_massey(&fib), vec![1.into(), 1.into()]);
+
+        let mut rng = XorShift::default();
+        for d in 1..10 {
+            let c: Vec<mint998244353> =
+                (0..d).map(|_| ModInt::new(rng.next_u64() as i64)).collect();
+            let mut s: Vec<mint998244353> =
+                (0..d).map(|_| ModInt::new(rng.next_u64() as i64)).collect();
+            for i in d..3 * d {
+                let x = (0..d).fold(ModInt::new(0), |acc, j| acc + c[j] * s[i - 1 - j]);
+                s.push(x);
+            }
+            let found = berlekamp_massey(&s);
+            assert!(found.len() <= d);
+            for i in found.len()..s.len() {
+                let x =
+                    (0..found.len()).fold(ModInt::new(0), |acc, j| acc + found[j] * s[i - 1 - j]);
+                assert_eq!(x, s[i]);
+            }
+        }
+        assert!(berlekamp_massey::<998244353>(&[0.into(), 0.into()]).is_empty());
+    }
+
+    #[test]
+    fn test_kth_term() {
+        let c: Vec<mint998244353> = vec![1.into(), 1.into()];
+        let init: Vec<mint998244353> = vec![0.into(), 1.into()];
+        assert_eq!(kth_term(&c, &init, 0), 0.into());
+        assert_eq!(kth_term(&c, &init, 10), 55.into());
+        assert_eq!(kth_term(&c, &init, 50), 12586269025_i64.into());
+
+        let c: Vec<mint1000000007> = vec![1.into(), 1.into()];
+        let init: Vec<mint1000000007> = vec![0.into(), 1.into()];
+        assert_eq!(
+            kth_term(&c, &init, 1_000_000_000_000_000_000),
+            209783453.into()
+        );
+
+        let mut rng = XorShift::default();
+        for d in [1, 3, 70] {
+            let c: Vec<mint998244353> =
+                (0..d).map(|_| ModInt::new(rng.next_u64() as i64)).collect();
+            let mut s: Vec<mint998244353> =
+                (0..d).map(|_| ModInt::new(rng.next_u64() as i64)).collect();
+            for i in d..500 {
+                let x = (0..d).fold(ModInt::new(0), |acc, j| acc + c[j] * s[i - 1 - j]);
+                s.push(x);
+            }
+            for k in [0, d as u64, 123, 499] {
+                assert_eq!(kth_term(&c, &s[..d], k), s[k as usize]);
+            }
+            assert_eq!(kth_term_of_sequence(&s[..2 * d], 499), s[499]);
+        }
+    }
+}
@@ -0,0 +1,245 @@
+pub mod matrix {
+    pub use crate::math::algebra::algebra::Semiring;
+    use crate::math::algebra::algebra::{One, Zero};
+    use std::ops::{Add, Index, IndexMut, Mul};
+
+    // (min, +) semiring, i64::MAX stands for infinity
+    #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+    pub struct MinPlus(pub i64);
+    impl MinPlus {
+        pub const INF: MinPlus = MinPlus(i64::MAX);
+    }
+    impl Add for MinPlus {
+        type Output = MinPlus;
+        fn add(self, rhs: Self) -> Self::Output {
+            self.min(rhs)
+        }
+    }
+    impl Mul for MinPlus {
+        type Output = MinPlus;
+        #[allow(clippy::suspicious_arithmetic_impl)]
+        fn mul(self, rhs: Self) -> Self::Output {
+            if self == Self::INF || rhs == Self::INF {
+                Self::INF
+            } else {
+                MinPlus(self.0 + rhs.0)
+            }
+        }
+    }
+    impl Zero for MinPlus {
+        fn zero() -> Self {
+            Self::INF
+        }
+        fn is_zero(&self) -> bool {
+            *self == Self::INF
+        }
+    }
+    impl One for MinPlus {
+        fn one() -> Self {
+            MinPlus(0)
+        }
+    }
+
+    #[derive(Clone, PartialEq, Eq, Debug)]
+    pub struct Matrix<T> {
+        rows: usize,
+        cols: usize,
+        a: Vec<Vec<T>>,
+    }
+
+    impl<T: Semiring> Matrix<T> {
+        pub fn new(rows: usize, cols: usize) -> Self {
+            Matrix {
+                rows,
+                cols,
+                a: vec![vec![T::zero(); cols]; rows],
+            }
+        }
+
+        pub fn identity(n: usize) -> Self {
+            let mut m = Self::new(n, n);
+            for i in 0..n {
+                m.a[i][i] = T::one();
+            }
+            m
+        }
+
+        pub fn rows(&self) -> usize {
+            self.rows
+        }
+
+        pub fn cols(&self) -> usize {
+            self.cols
+        }
+
+        pub fn transpose(&self) -> Self {
+            let mut t = Self::new(self.cols, self.rows);
+            for i in 0..self.rows {
+                for j in 0..self.cols {
+                    t.a[j][i] = self.a[i][j];
+                }
+            }
+            t
+        }
+
+        pub fn pow(&self, mut k: u64) -> Self {
+            assert_eq!(self.rows, self.cols);
+            let mut x = self.clone();
+            let mut ans = Self::identity(self.rows);
+            while k != 0 {
+                if (k & 1) == 1 {
+                    ans = &ans * &x;
+                }
+                x = &x * &x;
+                k >>= 1;
+            }
+            ans
+        }
+
+        pub fn mul_vec(&self, v: &[T]) -> Vec<T> {
+            assert_eq!(self.cols, v.len());
+            self.a
+                .iter()
+                .map(|row| {
+                    row.iter()
+                        .zip(v.iter())
+                        .fold(T::zero(), |s, (&x, &y)| s + x * y)
+                })
+                .collect()
+        }
+    }
+
+    impl<T: Semiring> From<Vec<Vec<T>>> for Matrix<T> {
+        fn from(a: Vec<Vec<T>>) -> Self {
+            let rows = a.len();
+            let cols = a.first().map_or(0, |r| r.len());
+            assert!(a.iter().all(|r| r.len() == cols));
+            Matrix { rows, cols, a }
+        }
+    }
+
+    impl<T> Index<usize> for Matrix<T> {
+        type Output = Vec<T>;
+        fn index(&self, i: usize) -> &Vec<T> {
+            &self.a[i]
+        }
+    }
+    impl<T> IndexMut<usize> for Matrix<T> {
+        fn index_mut(&mut self, i: usize) -> &mut Vec<T> {
+            &mut self.a[i]
+        }
+    }
+
+    impl<T: Semiring> Mul for &Matrix<T> {
+        type Output = Matrix<T>;
+        fn mul(self, rhs: Self) -> Self::Output {
+            assert_eq!(self.cols, rhs.rows);
+            let mut c = Matrix::new(self.rows, rhs.cols);
+            for i in 0..self.rows {
+                for k in 0..self.cols {
+                    let x = self.a[i][k];
+                    for j in 0..rhs.cols {
+                        c.a[i][j] = c.a[i][j] + x * rhs.a[k][j];
+                    }
+                }
+            }
+            c
+        }
+    }
+    impl<T: Semiring> Mul for Matrix<T> {
+        type Output = Matrix<T>;
+        fn mul(self, rhs: Self) -> Self::Output {
+            &self * &rhs
+        }
+    }
+    impl<T: Semiring> Add for &Matrix<T> {
+        type Output = Matrix<T>;
+        fn add(self, rhs: Self) -> Self::Output {
+            assert!(self.rows == rhs.rows && self.cols == rhs.cols);
+            let mut c = self.clone();
+            for i in 0..self.rows {
+                for j in 0..self.cols {
+                    c.a[i][j] = c.a[i][j] + rhs.a[i][j];
+                }
+            }
+            c
+        }
+    }
+    impl<T: Semiring> Add for Matrix<T> {
+        type Output = Matrix<T>;
+        fn add(self, rhs: Self) -> Self::Output {
+            &self + &rhs
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::matrix::*;
+    use crate::math::modint::modint::mint1000000007;
+    use crate::math::ratio::ratio::Ratio;
+
+    #[test]
+    fn test_matrix_pow() {
+        let fib = Matrix::<i64>::from(vec![vec![1, 1], vec![1, 0]]);
+        assert_eq!(fib.pow(0), Matrix::identity(2));
+        assert_eq!(fib.pow(10)[0][1], 55);
+        assert_eq!(fib.pow(50)[0][1], 12586269025);
+
+        let fib = Matrix::<mint1000000007>::from(vec![
+            vec![1.into(), 1.into()],
+            vec![1.into(), 0.into()],
+        ]);
+        assert_eq!(fib.pow(1_000_000_000_000_000_000)[0][1], 209783453.into());
+
+        let r = Matrix::<Ratio>::from(vec![
+            vec![Ratio::new(1, 2), Ratio::new(1, 3)],
+            vec![Ratio::new(0, 1), Ratio::new(1, 4)],
+        ]);
+        let r2 = r.pow(2);
+        assert_eq!(r2[0][1], Ratio::new(1, 6) + Ratio::new(1, 12));
+        assert_eq!(r2[1][1], Ratio::new(1, 16));
+    }
+
+    #[test]
+    fn test_matrix_ops() {
+        let a = Matrix::<i64>::from(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        let t = a.transpose();
+        assert_eq!(t, Matrix::from(vec![vec![1, 4], vec![2, 5], vec![3, 6]]));
+        assert_eq!(&a * &t, Matrix::from(vec![vec![14, 32], vec![32, 77]]));
+        assert_eq!(a.mul_vec(&[1, 1, 1]), vec![6, 15]);
+        assert_eq!(&a + &a, Matrix::from(vec![vec![2, 4, 6], vec![8, 10, 12]]));
+    }
+
+    #[test]
+    fn test_min_plus() {
+        let n = 5;
+        let edges = [
+            (0, 1, 3),
+            (1, 2, 4),
+            (2, 0, 1),
+            (0, 3, 7),
+            (3, 4, 2),
+            (4, 1, 1),
+            (2, 4, 9),
+        ];
+        let mut g = Matrix::<MinPlus>::new(n, n);
+        for &(u, v, w) in edges.iter() {
+            g[u][v] = g[u][v] + MinPlus(w);
+        }
+        let mut dp = vec![vec![MinPlus::INF; n]; n];
+        for (i, row) in dp.iter_mut().enumerate() {
+            row[i] = MinPlus(0);
+        }
+        for k in 0..10 {
+            assert_eq!(g.pow(k), Matrix::from(dp.clone()));
+            let mut next = vec![vec![MinPlus::INF; n]; n];
+            for (s, row) in dp.iter().enumerate() {
+                for &(u, v, w) in edges.iter() {
+                    next[s][v] = next[s][v] + row[u] * MinPlus(w);
+                }
+            }
+            dp = next;
+        }
+    }
+}
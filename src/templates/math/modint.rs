@@ -1,4 +1,6 @@
 pub mod modint {
+    use crate::math::algebra::algebra::{Field, One, Zero};
+
     pub fn qpow(mut x: i32, mut y: i32, p: i32) -> i32 {
         if y == 0 {
             return 1;
@@ -27,10 +29,26 @@ pub mod modint {
             ModInt(if result < 0 { result + P } else { result })
         }
         pub fn inv(self) -> Self {
-            ModInt(qpow(self.0, P - 2, P))
-        }
-        pub fn pow(self, y: Self) -> Self {
-            ModInt(qpow(self.0, y.0, P))
+            let (mut a, mut b) = (self.0 as i64, P as i64);
+            let (mut x, mut y) = (1_i64, 0_i64);
+            while b != 0 {
+                let t = a / b;
+                (a, b) = (b, a - t * b);
+                (x, y) = (y, x - t * y);
+            }
+            assert!(a == 1, "{} is not invertible", self.0);
+            Self::new(x)
+        }
+        pub fn pow(self, mut y: u64) -> Self {
+            let (mut x, mut ans) = (self, ModInt::new(1));
+            while y != 0 {
+                if (y & 1) == 1 {
+                    ans *= x;
+                }
+                x *= x;
+                y >>= 1;
+            }
+            ans
         }
     }
     impl<const P: i32> std::fmt::Display for ModInt<P> {
@@ -81,6 +99,7 @@ pub mod modint {
     }
     impl<const P: i32> std::ops::Div for ModInt<P> {
         type Output = ModInt<P>;
+        #[allow(clippy::suspicious_arithmetic_impl)]
         fn div(self, rhs: Self) -> Self::Output {
             self * rhs.inv()
         }
@@ -90,14 +109,14 @@ pub mod modint {
             *self = *self / rhs;
         }
     }
-    impl<const P: i32> Into<i32> for ModInt<P> {
-        fn into(self) -> i32 {
-            self.0
+    impl<const P: i32> From<ModInt<P>> for i32 {
+        fn from(value: ModInt<P>) -> Self {
+            value.0
         }
     }
-    impl<const P: i32> Into<i64> for ModInt<P> {
-        fn into(self) -> i64 {
-            self.0 as i64
+    impl<const P: i32> From<ModInt<P>> for i64 {
+        fn from(value: ModInt<P>) -> Self {
+            value.0 as i64
         }
     }
     impl<const P: i32> From<i32> for ModInt<P> {
@@ -114,6 +133,21 @@ pub mod modint {
     pub type mint998244353 = ModInt<998244353>;
     #[allow(non_camel_case_types)]
     pub type mint1000000007 = ModInt<1000000007>;
+
+    impl<const P: i32> Zero for ModInt<P> {
+        fn zero() -> Self {
+            ModInt(0)
+        }
+        fn is_zero(&self) -> bool {
+            self.0 == 0
+        }
+    }
+    impl<const P: i32> One for ModInt<P> {
+        fn one() -> Self {
+            ModInt::new(1)
+        }
+    }
+    impl<const P: i32> Field for ModInt<P> {}
 }
 
 #[cfg(test)]
@@ -130,12 +164,18 @@ mod tests {
         assert_eq!(x * y, 27.into());
         assert_eq!(x - y, 29.into());
         assert_eq!(x.inv(), 28.into());
-        assert_eq!(mint::new(10).pow(2.into()), 7.into());
+        assert_eq!(mint::new(10).pow(2), 7.into());
+        // exponents past the modulus, Fermat with 30 * 10^15 + 1
+        assert_eq!(mint::new(10).pow(30_000_000_000_000_001), 10.into());
         x += y;
         assert_eq!(x, 22.into());
         let z: mint = 10_i64.into();
         let a = mint::new(1e16 as i64);
         assert_eq!(z, 10.into());
         assert_eq!(a, 10.into());
+
+        type Mint12 = ModInt<12>;
+        assert_eq!(Mint12::new(5).inv(), 5.into());
+        assert_eq!(Mint12::new(7).inv(), 7.into());
     }
 }
@@ -0,0 +1,287 @@
+pub mod montgomery {
+    use crate::math::algebra::algebra::{Field, One, Zero};
+    use std::cell::Cell;
+    use std::marker::PhantomData;
+
+    // odd modulus m < 2^62, values kept as x * 2^64 mod m
+    #[derive(Copy, Clone, Debug)]
+    pub struct Montgomery64 {
+        m: u64,
+        inv: u64,
+        r2: u64,
+    }
+    impl Montgomery64 {
+        pub const fn new(m: u64) -> Self {
+            assert!(m & 1 == 1 && m < 1 << 62);
+            let mut inv = m;
+            let mut i = 0;
+            while i < 5 {
+                inv = inv.wrapping_mul(2_u64.wrapping_sub(m.wrapping_mul(inv)));
+                i += 1;
+            }
+            let r2 = ((u128::MAX % m as u128 + 1) % m as u128) as u64;
+            Montgomery64 { m, inv, r2 }
+        }
+        pub fn modulus(&self) -> u64 {
+            self.m
+        }
+        pub fn reduce(&self, t: u128) -> u64 {
+            let q = (t as u64).wrapping_mul(self.inv);
+            let a = (t >> 64) as u64;
+            let b = ((q as u128 * self.m as u128) >> 64) as u64;
+            if a >= b {
+                a - b
+            } else {
+                a + self.m - b
+            }
+        }
+        pub fn to_mont(&self, x: u64) -> u64 {
+            self.reduce(x as u128 * self.r2 as u128)
+        }
+        pub fn from_mont(&self, x: u64) -> u64 {
+            self.reduce(x as u128)
+        }
+        pub fn mul(&self, x: u64, y: u64) -> u64 {
+            self.reduce(x as u128 * y as u128)
+        }
+    }
+
+    pub trait Modulus: Copy + Eq + Default + std::fmt::Debug + std::hash::Hash {
+        fn mont() -> Montgomery64;
+    }
+
+    #[derive(Copy, Clone, Eq, PartialEq, Debug, Default, Hash)]
+    pub struct StaticModulus<const M: u64>;
+    impl<const M: u64> StaticModulus<M> {
+        const MONT: Montgomery64 = Montgomery64::new(M);
+    }
+    impl<const M: u64> Modulus for StaticModulus<M> {
+        fn mont() -> Montgomery64 {
+            Self::MONT
+        }
+    }
+
+    thread_local! {
+        static MONT: Cell<Montgomery64> = const { Cell::new(Montgomery64::new((1 << 61) - 1)) };
+    }
+
+    #[derive(Copy, Clone, Eq, PartialEq, Debug, Default, Hash)]
+    pub struct DynamicModulus;
+    impl DynamicModulus {
+        pub fn set_modulus(m: u64) {
+            MONT.with(|c| c.set(Montgomery64::new(m)));
+        }
+    }
+    impl Modulus for DynamicModulus {
+        fn mont() -> Montgomery64 {
+            MONT.with(|c| c.get())
+        }
+    }
+
+    #[derive(Copy, Clone, Eq, PartialEq, Debug, Default, Hash)]
+    pub struct MontgomeryModInt<M: Modulus>(u64, PhantomData<M>);
+    impl<M: Modulus> MontgomeryModInt<M> {
+        pub fn modulus() -> u64 {
+            M::mont().modulus()
+        }
+        pub fn new<T: Into<i128>>(value: T) -> Self {
+            let mont = M::mont();
+            let x = value.into().rem_euclid(mont.modulus() as i128) as u64;
+            MontgomeryModInt(mont.to_mont(x), PhantomData)
+        }
+        pub fn val(self) -> u64 {
+            M::mont().from_mont(self.0)
+        }
+        // works for any modulus coprime with self
+        pub fn inv(self) -> Self {
+            let (mut a, mut b) = (self.val() as i128, Self::modulus() as i128);
+            let (mut x, mut y) = (1_i128, 0_i128);
+            while b != 0 {
+                let t = a / b;
+                (a, b) = (b, a - t * b);
+                (x, y) = (y, x - t * y);
+            }
+            assert!(a == 1, "{} is not invertible", self.val());
+            Self::new(x)
+        }
+        pub fn pow(self, mut y: u64) -> Self {
+            let mont = M::mont();
+            let mut x = self.0;
+            let mut ans = mont.to_mont(1 % mont.modulus());
+            while y != 0 {
+                if (y & 1) == 1 {
+                    ans = mont.mul(ans, x);
+                }
+                x = mont.mul(x, x);
+                y >>= 1;
+            }
+            MontgomeryModInt(ans, PhantomData)
+        }
+    }
+    impl<M: Modulus> std::fmt::Display for MontgomeryModInt<M> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{}", self.val())
+        }
+    }
+    impl<M: Modulus> std::ops::Add for MontgomeryModInt<M> {
+        type Output = MontgomeryModInt<M>;
+        fn add(self, rhs: Self) -> Self::Output {
+            let m = Self::modulus();
+            let result = self.0 + rhs.0;
+            MontgomeryModInt(if result >= m { result - m } else { result }, PhantomData)
+        }
+    }
+    impl<M: Modulus> std::ops::AddAssign for MontgomeryModInt<M> {
+        fn add_assign(&mut self, rhs: Self) {
+            *self = *self + rhs;
+        }
+    }
+    impl<M: Modulus> std::ops::Sub for MontgomeryModInt<M> {
+        type Output = MontgomeryModInt<M>;
+        fn sub(self, rhs: Self) -> Self::Output {
+            let result = if self.0 >= rhs.0 {
+                self.0 - rhs.0
+            } else {
+                self.0 + Self::modulus() - rhs.0
+            };
+            MontgomeryModInt(result, PhantomData)
+        }
+    }
+    impl<M: Modulus> std::ops::SubAssign for MontgomeryModInt<M> {
+        fn sub_assign(&mut self, rhs: Self) {
+            *self = *self - rhs;
+        }
+    }
+    impl<M: Modulus> std::ops::Neg for MontgomeryModInt<M> {
+        type Output = MontgomeryModInt<M>;
+        fn neg(self) -> Self::Output {
+            MontgomeryModInt::default() - self
+        }
+    }
+    impl<M: Modulus> std::ops::Mul for MontgomeryModInt<M> {
+        type Output = MontgomeryModInt<M>;
+        fn mul(self, rhs: Self) -> Self::Output {
+            MontgomeryModInt(M::mont().mul(self.0, rhs.0), PhantomData)
+        }
+    }
+    impl<M: Modulus> std::ops::MulAssign for MontgomeryModInt<M> {
+        fn mul_assign(&mut self, rhs: Self) {
+            *self = *self * rhs;
+        }
+    }
+    impl<M: Modulus> std::ops::Div for MontgomeryModInt<M> {
+        type Output = MontgomeryModInt<M>;
+        #[allow(clippy::suspicious_arithmetic_impl)]
+        fn div(self, rhs: Self) -> Self::Output {
+            self * rhs.inv()
+        }
+    }
+    impl<M: Modulus> std::ops::DivAssign for MontgomeryModInt<M> {
+        fn div_assign(&mut self, rhs: Self) {
+            *self = *self / rhs;
+        }
+    }
+    impl<M: Modulus> From<MontgomeryModInt<M>> for u64 {
+        fn from(value: MontgomeryModInt<M>) -> Self {
+            value.val()
+        }
+    }
+    impl<M: Modulus> From<i32> for MontgomeryModInt<M> {
+        fn from(value: i32) -> Self {
+            MontgomeryModInt::new(value)
+        }
+    }
+    impl<M: Modulus> From<i64> for MontgomeryModInt<M> {
+        fn from(value: i64) -> Self {
+            MontgomeryModInt::new(value)
+        }
+    }
+    impl<M: Modulus> From<u64> for MontgomeryModInt<M> {
+        fn from(value: u64) -> Self {
+            MontgomeryModInt::new(value)
+        }
+    }
+
+    pub type MontModInt<const M: u64> = MontgomeryModInt<StaticModulus<M>>;
+    pub type DynMontModInt = MontgomeryModInt<DynamicModulus>;
+    #[allow(non_camel_case_types)]
+    pub type mint2305843009213693951 = MontModInt<2305843009213693951>;
+
+    impl<M: Modulus> Zero for MontgomeryModInt<M> {
+        fn zero() -> Self {
+            MontgomeryModInt(0, PhantomData)
+        }
+        fn is_zero(&self) -> bool {
+            self.val() == 0
+        }
+    }
+    impl<M: Modulus> One for MontgomeryModInt<M> {
+        fn one() -> Self {
+            MontgomeryModInt::new(1)
+        }
+    }
+    impl<M: Modulus> Field for MontgomeryModInt<M> {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::montgomery::*;
+    use crate::test_util::test_util::XorShift;
+
+    #[test]
+    fn test_montgomery() {
+        type Mint = MontModInt<31>;
+        let mut x: Mint = 10.into();
+        let y: Mint = 12.into();
+        assert_eq!(x + y, 22.into());
+        assert_eq!(x * y, 27.into());
+        assert_eq!(x - y, 29.into());
+        assert_eq!(-y, 19.into());
+        assert_eq!(x.inv(), 28.into());
+        assert_eq!(x / y * y, x);
+        assert_eq!(Mint::new(10).pow(2), 7.into());
+        x += y;
+        assert_eq!(x, 22.into());
+        assert_eq!(Mint::new(-1_i64).val(), 30);
+        assert_eq!(format!("{}", Mint::new(1e16 as i64)), "10");
+
+        let p = (1_u64 << 61) - 1;
+        let a = mint2305843009213693951::new(p - 1);
+        assert_eq!(a * a, 1.into());
+        assert_eq!(mint2305843009213693951::new(3).pow(p - 1), 1.into());
+    }
+
+    #[test]
+    fn test_montgomery_random() {
+        let mut rng = XorShift::default();
+        for _ in 0..100 {
+            let m = (rng.next_u64() >> 2) | 1;
+            DynamicModulus::set_modulus(m);
+            assert_eq!(DynMontModInt::modulus(), m);
+            for _ in 0..100 {
+                let x = rng.below(m);
+                let y = rng.below(m);
+                let (a, b) = (DynMontModInt::new(x), DynMontModInt::new(y));
+                assert_eq!((a * b).val(), (x as u128 * y as u128 % m as u128) as u64);
+                assert_eq!((a + b).val(), ((x as u128 + y as u128) % m as u128) as u64);
+                assert_eq!(
+                    (a - b).val(),
+                    ((x as u128 + m as u128 - y as u128) % m as u128) as u64
+                );
+            }
+        }
+        DynamicModulus::set_modulus(1);
+        assert_eq!(DynMontModInt::new(5).pow(3).val(), 0);
+    }
+
+    #[test]
+    fn test_montgomery_templates() {
+        use crate::math::matrix::matrix::Matrix;
+        type Mint = MontModInt<1000000007>;
+        let fib = Matrix::from(vec![vec![Mint::new(1); 2], vec![1.into(), 0.into()]]);
+        assert_eq!(fib.pow(90)[0][1], Mint::new(2880067194370816120_i64));
+        let m = Matrix::from(vec![vec![Mint::new(2), 3.into()], vec![1.into(), 4.into()]]);
+        assert_eq!(m.determinant(), 5.into());
+        assert_eq!(&m * &m.inverse().unwrap(), Matrix::identity(2));
+    }
+}
@@ -0,0 +1,281 @@
+pub mod number_theory {
+    use crate::math::modint::modint::{qpow, ModInt};
+    use crate::math::prime::prime::factorize;
+    use crate::math::qpow::qpow::{mul_mod_u64, qpow_u64};
+    use std::collections::HashMap;
+
+    // (g, x, y) with a * x + b * y = g = gcd(a, b) >= 0
+    pub fn ext_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+        let (mut r0, mut r1) = (a as i128, b as i128);
+        let (mut x0, mut x1) = (1_i128, 0_i128);
+        let (mut y0, mut y1) = (0_i128, 1_i128);
+        while r1 != 0 {
+            let q = r0 / r1;
+            (r0, r1) = (r1, r0 - q * r1);
+            (x0, x1) = (x1, x0 - q * x1);
+            (y0, y1) = (y1, y0 - q * y1);
+        }
+        if r0 < 0 {
+            (r0, x0, y0) = (-r0, -x0, -y0);
+        }
+        (r0 as i64, x0 as i64, y0 as i64)
+    }
+
+    // inverse of a modulo any m >= 1, None if gcd(a, m) != 1
+    pub fn inv_mod(a: i64, m: i64) -> Option<i64> {
+        assert!(m >= 1);
+        let (g, x, _) = ext_gcd(a.rem_euclid(m), m);
+        if g != 1 {
+            return None;
+        }
+        Some(x.rem_euclid(m))
+    }
+
+    // x = r_i (mod m_i) for all i as (x, lcm), moduli need not be coprime, None if inconsistent,
+    // panics if the lcm does not fit in i64
+    pub fn crt(congruences: &[(i64, i64)]) -> Option<(i64, i64)> {
+        let (mut r0, mut m0) = (0_i128, 1_i128);
+        for &(r1, m1) in congruences {
+            assert!(m1 >= 1);
+            let (mut r1, mut m1) = ((r1 as i128).rem_euclid(m1 as i128), m1 as i128);
+            if m0 < m1 {
+                (r0, r1) = (r1, r0);
+                (m0, m1) = (m1, m0);
+            }
+            if m0 % m1 == 0 {
+                if r0 % m1 != r1 {
+                    return None;
+                }
+                continue;
+            }
+            let (g, p, _) = ext_gcd(m0 as i64, m1 as i64);
+            let (g, p) = (g as i128, p as i128);
+            if (r1 - r0) % g != 0 {
+                return None;
+            }
+            let u1 = m1 / g;
+            let x = (r1 - r0) / g % u1 * p % u1;
+            r0 += x * m0;
+            m0 *= u1;
+            assert!(m0 <= i64::MAX as i128, "lcm of the moduli overflows i64");
+            r0 = r0.rem_euclid(m0);
+        }
+        Some((r0 as i64, m0 as i64))
+    }
+
+    // smallest x >= 0 with a^x = b (mod m), a and m need not be coprime
+    pub fn discrete_log(a: i64, b: i64, m: i64) -> Option<i64> {
+        assert!(m >= 1);
+        let mut m = m as u64;
+        let a = a.rem_euclid(m as i64) as u64;
+        let mut b = b.rem_euclid(m as i64) as u64;
+        let mut k = 1 % m;
+        let mut add = 0;
+        loop {
+            let g = ext_gcd(a as i64, m as i64).0 as u64;
+            if g == 1 {
+                break;
+            }
+            if b == k {
+                return Some(add);
+            }
+            if b % g != 0 {
+                return None;
+            }
+            b /= g;
+            m /= g;
+            add += 1;
+            k = mul_mod_u64(k, a / g, m);
+        }
+        if b == k % m {
+            return Some(add);
+        }
+        let n = (m as f64).sqrt() as u64 + 1;
+        let mut baby = HashMap::new();
+        let mut cur = b % m;
+        for j in 0..n {
+            baby.insert(cur, j);
+            cur = mul_mod_u64(cur, a, m);
+        }
+        let an = qpow_u64(a, n, m);
+        let mut cur = k % m;
+        for i in 1..=n {
+            cur = mul_mod_u64(cur, an, m);
+            if let Some(&j) = baby.get(&cur) {
+                return Some(add + (i * n - j) as i64);
+            }
+        }
+        None
+    }
+
+    // Tonelli-Shanks, P must be prime
+    pub fn sqrt_mod<const P: i32>(a: ModInt<P>) -> Option<ModInt<P>> {
+        let a: i64 = a.into();
+        let p = P as i64;
+        if a == 0 || p == 2 {
+            return Some(ModInt::new(a));
+        }
+        let pw = |x: i64, e: i64| qpow(x as i32, e as i32, P) as i64;
+        if pw(a, (p - 1) / 2) != 1 {
+            return None;
+        }
+        let mut q = p - 1;
+        let mut s = 0;
+        while q & 1 == 0 {
+            q >>= 1;
+            s += 1;
+        }
+        let z = (2..p).find(|&z| pw(z, (p - 1) / 2) == p - 1).unwrap();
+        let mut m = s;
+        let mut c = pw(z, q);
+        let mut t = pw(a, q);
+        let mut r = pw(a, (q + 1) / 2);
+        while t != 1 {
+            let mut i = 0;
+            let mut tt = t;
+            while tt != 1 {
+                tt = tt * tt % p;
+                i += 1;
+            }
+            let b = pw(c, 1 << (m - i - 1));
+            m = i;
+            c = b * b % p;
+            t = t * c % p;
+            r = r * b % p;
+        }
+        Some(ModInt::new(r))
+    }
+
+    // smallest primitive root of a prime p
+    pub fn primitive_root(p: u64) -> u64 {
+        if p == 2 {
+            return 1;
+        }
+        let factors = factorize(p - 1);
+        (2..p)
+            .find(|&g| {
+                factors
+                    .iter()
+                    .all(|&(q, _)| qpow_u64(g, (p - 1) / q, p) != 1)
+            })
+            .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::number_theory::*;
+
+    #[test]
+    fn test_ext_gcd() {
+        for a in -30..=30_i64 {
+            for b in -30..=30_i64 {
+                let (g, x, y) = ext_gcd(a, b);
+                assert_eq!(a * x + b * y, g);
+                assert!(g >= 0);
+                if g != 0 {
+                    assert!(a % g == 0 && b % g == 0);
+                }
+            }
+        }
+        let (g, x, y) = ext_gcd(i64::MAX, i64::MAX - 1);
+        assert_eq!(g, 1);
+        assert_eq!(
+            i64::MAX as i128 * x as i128 + (i64::MAX - 1) as i128 * y as i128,
+            1
+        );
+    }
+
+    #[test]
+    fn test_inv_mod() {
+        assert_eq!(inv_mod(3, 7), Some(5));
+        assert_eq!(inv_mod(-3, 7), Some(2));
+        assert_eq!(inv_mod(5, 12), Some(5));
+        assert_eq!(inv_mod(4, 12), None);
+        assert_eq!(inv_mod(5, 1), Some(0));
+        let m = 1_000_000_000_000_000_003_i64;
+        let x = inv_mod(2, m).unwrap();
+        assert_eq!((2 * x as i128 % m as i128) as i64, 1);
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt(&[]), Some((0, 1)));
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt(&[(1, 4), (3, 6)]), Some((9, 12)));
+        assert_eq!(crt(&[(1, 4), (2, 6)]), None);
+        for m1 in 1..15_i64 {
+            for m2 in 1..15_i64 {
+                for r1 in -m1..m1 {
+                    for r2 in 0..m2 {
+                        let lcm = m1 * m2 / ext_gcd(m1, m2).0;
+                        let expected = (0..lcm)
+                            .find(|x| (x - r1).rem_euclid(m1) == 0 && x % m2 == r2)
+                            .map(|x| (x, lcm));
+                        assert_eq!(crt(&[(r1, m1), (r2, m2)]), expected);
+                    }
+                }
+            }
+        }
+        let p = 1_000_000_007;
+        let q = 998_244_353;
+        assert_eq!(crt(&[(5, p), (5, q)]), Some((5, p * q)));
+        let big = 1_000_000_000_000_000_003;
+        assert_eq!(
+            crt(&[(1, big), (2, 9)]),
+            Some((7_000_000_000_000_000_022, 9 * big))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "overflows i64")]
+    fn test_crt_overflow() {
+        crt(&[(1, 1_000_000_000_000_000_003), (2, 1_000_000_007)]);
+    }
+
+    #[test]
+    fn test_discrete_log() {
+        for m in 1..40_i64 {
+            for a in 0..m {
+                let mut pw = vec![1 % m];
+                for _ in 0..2 * m {
+                    pw.push(pw.last().unwrap() * a % m);
+                }
+                for b in 0..m {
+                    let expected = pw.iter().position(|&x| x == b).map(|x| x as i64);
+                    assert_eq!(discrete_log(a, b, m), expected);
+                }
+            }
+        }
+        let p = 998244353;
+        let x = discrete_log(3, 123456789, p).unwrap();
+        assert_eq!(crate::math::qpow::qpow::qpow(3, x, p), 123456789);
+    }
+
+    #[test]
+    fn test_sqrt_mod() {
+        use crate::math::modint::modint::{mint998244353, ModInt};
+        for a in 0..13 {
+            let a = ModInt::<13>::new(a);
+            let expected = (0..13).map(ModInt::<13>::new).any(|x| x * x == a);
+            match sqrt_mod(a) {
+                Some(r) => assert_eq!(r * r, a),
+                None => assert!(!expected),
+            }
+        }
+        let a = mint998244353::new(123456789);
+        let r = sqrt_mod(a * a).unwrap();
+        assert!(r == a || r == -a);
+        assert_eq!(sqrt_mod(mint998244353::new(3)), None);
+    }
+
+    #[test]
+    fn test_primitive_root() {
+        assert_eq!(primitive_root(2), 1);
+        assert_eq!(primitive_root(7), 3);
+        assert_eq!(primitive_root(998244353), 3);
+        assert_eq!(primitive_root(1000000007), 5);
+        assert_eq!(primitive_root(754974721), 11);
+        assert_eq!(primitive_root((1 << 61) - 1), 37);
+    }
+}
@@ -0,0 +1,208 @@
+pub mod prime {
+    use crate::math::qpow::qpow::{mul_mod_u64, qpow_u64};
+
+    fn gcd(mut x: u64, mut y: u64) -> u64 {
+        while y != 0 {
+            (x, y) = (y, x % y);
+        }
+        x
+    }
+
+    // deterministic for every u64
+    pub fn is_prime(n: u64) -> bool {
+        if n < 2 {
+            return false;
+        }
+        for p in [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37] {
+            if n % p == 0 {
+                return n == p;
+            }
+        }
+        let s = (n - 1).trailing_zeros();
+        let d = (n - 1) >> s;
+        'outer: for a in [2, 325, 9375, 28178, 450775, 9780504, 1795265022] {
+            let mut x = qpow_u64(a, d, n);
+            if x == 0 || x == 1 || x == n - 1 {
+                continue;
+            }
+            for _ in 1..s {
+                x = mul_mod_u64(x, x, n);
+                if x == n - 1 {
+                    continue 'outer;
+                }
+            }
+            return false;
+        }
+        true
+    }
+
+    // a non-trivial factor of an odd composite n, Pollard rho with Brent's cycle detection
+    fn pollard_rho(n: u64) -> u64 {
+        const M: u64 = 128;
+        let f = |x: u64, c: u64| ((mul_mod_u64(x, x, n) as u128 + c as u128) % n as u128) as u64;
+        for c in 1..n {
+            let (mut x, mut y, mut ys, mut q) = (0, 2, 0, 1);
+            let mut g = 1;
+            let mut r = 1;
+            while g == 1 {
+                x = y;
+                for _ in 0..r {
+                    y = f(y, c);
+                }
+                let mut k = 0;
+                while k < r && g == 1 {
+                    ys = y;
+                    for _ in 0..M.min(r - k) {
+                        y = f(y, c);
+                        q = mul_mod_u64(q, x.abs_diff(y), n);
+                    }
+                    g = gcd(q, n);
+                    k += M;
+                }
+                r <<= 1;
+            }
+            if g == n {
+                g = 1;
+                while g == 1 {
+                    ys = f(ys, c);
+                    g = gcd(x.abs_diff(ys), n);
+                }
+            }
+            if g != n {
+                return g;
+            }
+        }
+        unreachable!()
+    }
+
+    fn factorize_into(n: u64, factors: &mut Vec<u64>) {
+        if n == 1 {
+            return;
+        }
+        if is_prime(n) {
+            factors.push(n);
+            return;
+        }
+        let d = pollard_rho(n);
+        factorize_into(d, factors);
+        factorize_into(n / d, factors);
+    }
+
+    // prime factorization as (p, e) pairs sorted by p
+    pub fn factorize(mut n: u64) -> Vec<(u64, u32)> {
+        assert!(n > 0);
+        let mut primes = vec![];
+        for p in 2..64 {
+            while n % p == 0 {
+                primes.push(p);
+                n /= p;
+            }
+        }
+        factorize_into(n, &mut primes);
+        primes.sort_unstable();
+        let mut result: Vec<(u64, u32)> = vec![];
+        for p in primes {
+            match result.last_mut() {
+                Some((q, e)) if *q == p => *e += 1,
+                _ => result.push((p, 1)),
+            }
+        }
+        result
+    }
+
+    pub fn divisors(n: u64) -> Vec<u64> {
+        let mut result = vec![1];
+        for (p, e) in factorize(n) {
+            let len = result.len();
+            let mut pk = 1;
+            for _ in 0..e {
+                pk *= p;
+                for i in 0..len {
+                    result.push(result[i] * pk);
+                }
+            }
+        }
+        result.sort_unstable();
+        result
+    }
+
+    pub fn euler_phi(n: u64) -> u64 {
+        factorize(n)
+            .into_iter()
+            .fold(n, |phi, (p, _)| phi / p * (p - 1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::prime::*;
+
+    #[test]
+    fn test_is_prime() {
+        let mut sieve = vec![true; 10000];
+        sieve[0] = false;
+        sieve[1] = false;
+        for i in 2..10000 {
+            if sieve[i] {
+                for j in (i * i..10000).step_by(i) {
+                    sieve[j] = false;
+                }
+            }
+            assert_eq!(is_prime(i as u64), sieve[i]);
+        }
+        assert!(is_prime(998244353));
+        assert!(is_prime(1000000007));
+        assert!(is_prime((1 << 61) - 1));
+        assert!(is_prime(18446744073709551557));
+        assert!(!is_prime(3215031751));
+        assert!(!is_prime(3825123056546413051));
+        assert!(!is_prime(18446744073709551615));
+    }
+
+    #[test]
+    fn test_factorize() {
+        assert_eq!(factorize(1), vec![]);
+        assert_eq!(factorize(360), vec![(2, 3), (3, 2), (5, 1)]);
+        assert_eq!(factorize(998244353), vec![(998244353, 1)]);
+        assert_eq!(
+            factorize(1000000007 * 998244353),
+            vec![(998244353, 1), (1000000007, 1)]
+        );
+        assert_eq!(factorize(4295098369), vec![(65537, 2)]);
+        assert_eq!(
+            factorize(18446744073709551615),
+            vec![
+                (3, 1),
+                (5, 1),
+                (17, 1),
+                (257, 1),
+                (641, 1),
+                (65537, 1),
+                (6700417, 1)
+            ]
+        );
+        for n in 1..2000_u64 {
+            let product = factorize(n).iter().fold(1, |acc, &(p, e)| acc * p.pow(e));
+            assert_eq!(product, n);
+        }
+    }
+
+    #[test]
+    fn test_divisors_phi() {
+        assert_eq!(divisors(1), vec![1]);
+        assert_eq!(divisors(12), vec![1, 2, 3, 4, 6, 12]);
+        assert_eq!(divisors(998244353), vec![1, 998244353]);
+        for n in 1..500_u64 {
+            let expected: Vec<u64> = (1..=n).filter(|d| n % d == 0).collect();
+            assert_eq!(divisors(n), expected);
+            let phi = (1..=n).filter(|&k| {
+                let (mut a, mut b) = (n, k);
+                while b != 0 {
+                    (a, b) = (b, a % b);
+                }
+                a == 1
+            });
+            assert_eq!(euler_phi(n), phi.count() as u64);
+        }
+    }
+}
@@ -0,0 +1,204 @@
+pub mod prime_count {
+    use crate::math::algebra::algebra::Ring;
+    use crate::math::sieve::sieve::LinearSieve;
+
+    pub fn isqrt(n: u64) -> u64 {
+        let mut r = (n as f64).sqrt() as u64;
+        while r * r > n {
+            r -= 1;
+        }
+        while (r + 1) * (r + 1) <= n {
+            r += 1;
+        }
+        r
+    }
+
+    // x reduced into T, overflows only if T cannot hold x itself
+    fn from_i128<T: Ring + From<i64>>(x: i128) -> T {
+        match i64::try_from(x) {
+            Ok(x) => T::from(x),
+            Err(_) => {
+                let (q, r) = (x.div_euclid(1 << 62), x.rem_euclid(1 << 62));
+                from_i128::<T>(q) * T::from(1 << 62) + T::from(r as i64)
+            }
+        }
+    }
+
+    // sum of g(p) over primes p <= v for every v = n / k, in O(n^{3/4})
+    #[derive(Clone, Debug)]
+    pub struct LucyTable<T> {
+        n: u64,
+        sq: u64,
+        primes: Vec<u64>,
+        // small[v] for v <= sq, large[k] for v = n / k > sq
+        small: Vec<T>,
+        large: Vec<T>,
+    }
+
+    impl<T: Ring> LucyTable<T> {
+        // g completely multiplicative, prefix(v) = g(2) + ... + g(v)
+        pub fn new(n: u64, g: impl Fn(u64) -> T, prefix: impl Fn(u64) -> T) -> Self {
+            let sq = isqrt(n);
+            let primes: Vec<u64> = LinearSieve::new(sq as usize)
+                .primes()
+                .iter()
+                .map(|&p| p as u64)
+                .collect();
+            let small: Vec<T> = (0..=sq)
+                .map(|v| if v < 2 { T::zero() } else { prefix(v) })
+                .collect();
+            let large: Vec<T> = std::iter::once(T::zero())
+                .chain((1..=sq).map(|k| prefix(n / k)))
+                .collect();
+            let mut table = LucyTable {
+                n,
+                sq,
+                primes,
+                small,
+                large,
+            };
+            for i in 0..table.primes.len() {
+                let p = table.primes[i];
+                let (gp, base) = (g(p), table.small[p as usize - 1]);
+                let p2 = p * p;
+                for k in 1..=sq {
+                    if n / k < p2 {
+                        break;
+                    }
+                    let d = table.get(n / k / p) - base;
+                    table.large[k as usize] = table.large[k as usize] - gp * d;
+                }
+                for v in (p2..=sq).rev() {
+                    let d = table.small[(v / p) as usize] - base;
+                    table.small[v as usize] = table.small[v as usize] - gp * d;
+                }
+            }
+            table
+        }
+
+        // v must be of the form n / k, which holds for every v <= sqrt(n)
+        pub fn get(&self, v: u64) -> T {
+            if v <= self.sq {
+                self.small[v as usize]
+            } else {
+                self.large[(self.n / v) as usize]
+            }
+        }
+
+        pub fn primes(&self) -> &[u64] {
+            &self.primes
+        }
+    }
+
+    pub fn prime_count(n: u64) -> u64 {
+        LucyTable::<i64>::new(n, |_| 1, |v| v as i64 - 1).get(n) as u64
+    }
+
+    // the table holds sums of all integers up to n, so it runs exactly in i128
+    // (fine up to n ~ 1e13) and only the answer is reduced into T
+    pub fn prime_sum<T: Ring + From<i64>>(n: u64) -> T {
+        let prefix = |v: u64| v as i128 * (v as i128 + 1) / 2 - 1;
+        from_i128(LucyTable::<i128>::new(n, |p| p as i128, prefix).get(n))
+    }
+
+    // sum of the multiplicative f over 1..=n, given the primes up to sqrt(n),
+    // prime_prefix(v) = sum of f(p) over primes p <= v for v = n / k and f_pe(p, e, p^e) = f(p^e)
+    pub fn min25_sum<T: Ring>(
+        n: u64,
+        primes: &[u64],
+        prime_prefix: impl Fn(u64) -> T,
+        f_pe: impl Fn(u64, u32, u64) -> T,
+    ) -> T {
+        // sum of f(i) over 2 <= i <= v whose smallest prime factor is at least primes[j]
+        fn rec<T: Ring>(
+            v: u64,
+            j: usize,
+            primes: &[u64],
+            prime_prefix: &impl Fn(u64) -> T,
+            f_pe: &impl Fn(u64, u32, u64) -> T,
+        ) -> T {
+            let below = if j == 0 {
+                T::zero()
+            } else {
+                prime_prefix(primes[j - 1])
+            };
+            let mut ans = prime_prefix(v) - below;
+            for (k, &p) in primes.iter().enumerate().skip(j) {
+                if p * p > v {
+                    break;
+                }
+                let (mut pe, mut e) = (p, 1);
+                while pe * p <= v {
+                    ans = ans
+                        + f_pe(p, e, pe) * rec(v / pe, k + 1, primes, prime_prefix, f_pe)
+                        + f_pe(p, e + 1, pe * p);
+                    pe *= p;
+                    e += 1;
+                }
+            }
+            ans
+        }
+        if n == 0 {
+            return T::zero();
+        }
+        T::one() + rec(n, 0, primes, &prime_prefix, &f_pe)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::prime_count::*;
+    use crate::math::modint::modint::{mint998244353, ModInt};
+    use crate::math::sieve::sieve::LinearSieve;
+
+    #[test]
+    fn test_prime_count() {
+        let n = 10000;
+        let sieve = LinearSieve::new(n);
+        for v in (0..=n as u64).step_by(37).chain([1, 2, 3, 4, 99, 100, 101]) {
+            let primes = sieve.primes().iter().filter(|&&p| p as u64 <= v);
+            assert_eq!(prime_count(v), primes.clone().count() as u64);
+            assert_eq!(prime_sum::<i64>(v), primes.map(|&p| p as i64).sum::<i64>());
+        }
+        assert_eq!(prime_count(1_000_000_000), 50847534);
+        assert_eq!(prime_sum::<i64>(1_000_000_000), 24739512092254535);
+        assert_eq!(
+            prime_sum::<mint998244353>(1_000_000_000),
+            ModInt::new(24739512092254535_i64)
+        );
+        // the sum of all integers up to 1e10 no longer fits in i64
+        assert_eq!(prime_count(10_000_000_000), 455052511);
+        assert_eq!(prime_sum::<i64>(10_000_000_000), 2220822432581729238);
+        assert_eq!(
+            prime_sum::<mint998244353>(10_000_000_000),
+            ModInt::new(2220822432581729238_i64)
+        );
+    }
+
+    #[test]
+    fn test_min25_sum() {
+        let n = 5000;
+        let sieve = LinearSieve::new(n);
+        let phi = sieve.euler_phi();
+        let d = sieve.divisor_count();
+        for v in (0..=n as u64).step_by(29).chain([1, 2, 3, 4, 5]) {
+            let counts = LucyTable::<i64>::new(v, |_| 1, |x| x as i64 - 1);
+            let sums = LucyTable::<i64>::new(v, |p| p as i64, |x| (x * (x + 1) / 2) as i64 - 1);
+            let phi_sum = min25_sum(
+                v,
+                counts.primes(),
+                |x| sums.get(x) - counts.get(x),
+                |p, _, pe| (pe - pe / p) as i64,
+            );
+            let d_sum = min25_sum(
+                v,
+                counts.primes(),
+                |x| 2 * counts.get(x),
+                |_, e, _| e as i64 + 1,
+            );
+            let upto = v as usize + 1;
+            assert_eq!(phi_sum, phi[..upto].iter().sum::<u64>() as i64);
+            assert_eq!(d_sum, d[..upto].iter().sum::<u64>() as i64);
+        }
+    }
+}
@@ -11,7 +11,24 @@ pub mod qpow {
             x = x * x % p;
             y >>= 1;
         }
-        return ans;
+        ans
+    }
+
+    pub fn mul_mod_u64(x: u64, y: u64, p: u64) -> u64 {
+        (x as u128 * y as u128 % p as u128) as u64
+    }
+
+    pub fn qpow_u64(mut x: u64, mut y: u64, p: u64) -> u64 {
+        let mut ans = 1 % p;
+        x %= p;
+        while y != 0 {
+            if (y & 1) == 1 {
+                ans = mul_mod_u64(ans, x, p);
+            }
+            x = mul_mod_u64(x, x, p);
+            y >>= 1;
+        }
+        ans
     }
 }
 
@@ -31,4 +48,14 @@ mod tests {
 
         // Add more test cases here...
     }
+
+    #[test]
+    fn test_qpow_u64() {
+        let p = (1_u64 << 61) - 1;
+        assert_eq!(mul_mod_u64(p - 1, p - 1, p), 1);
+        assert_eq!(qpow_u64(3, p - 1, p), 1);
+        assert_eq!(qpow_u64(2, 61, p), 1);
+        assert_eq!(qpow_u64(5, 0, 1), 0);
+        assert_eq!(qpow_u64(2, 3, 5), 3);
+    }
 }
@@ -1,30 +1,111 @@
 pub mod ratio {
+    use crate::math::algebra::algebra::{Field, One, Zero};
+    use std::cmp::Ordering;
     use std::ops::*;
-    #[derive(Copy, Clone, PartialEq, Eq, Ord, Debug)]
-    pub struct Ratio {
-        num: i64,
-        den: i64,
-    }
-    pub fn gcd(x: i64, y: i64) -> i64 {
-        match y {
-            0 => x,
-            y => gcd(y, x % y),
-        }
-    }
-    pub fn simplify(x: i64, y: i64) -> (i64, i64) {
-        match x {
-            0 => (0, 1),
-            x => {
-                let g = gcd(x, y);
-                (x / g, y / g)
+    use std::str::FromStr;
+
+    pub trait Integer:
+        Zero
+        + One
+        + Clone
+        + Ord
+        + Add<Output = Self>
+        + Sub<Output = Self>
+        + Mul<Output = Self>
+        + Div<Output = Self>
+        + Rem<Output = Self>
+        + Neg<Output = Self>
+    {
+        fn to_f64(&self) -> f64;
+        // compares a / b with c / d for b, d > 0 by continued fractions, never overflows
+        fn cmp_fraction(a: &Self, b: &Self, c: &Self, d: &Self) -> Ordering {
+            let (mut a, mut b, mut c, mut d) = (a.clone(), b.clone(), c.clone(), d.clone());
+            loop {
+                let (q1, r1) = floor_div_rem(a, b.clone());
+                let (q2, r2) = floor_div_rem(c, d.clone());
+                if q1 != q2 {
+                    return q1.cmp(&q2);
+                }
+                match (r1.is_zero(), r2.is_zero()) {
+                    (true, true) => return Ordering::Equal,
+                    (true, false) => return Ordering::Less,
+                    (false, true) => return Ordering::Greater,
+                    // r1 / b < r2 / d iff d / r2 < b / r1
+                    (false, false) => (a, b, c, d) = (d, r2, b, r1),
+                }
             }
         }
     }
-    impl Ratio {
-        pub fn new(num: i64, den: i64) -> Self {
-            assert!(den != 0.into());
+
+    impl Integer for i32 {
+        fn to_f64(&self) -> f64 {
+            *self as f64
+        }
+        fn cmp_fraction(a: &Self, b: &Self, c: &Self, d: &Self) -> Ordering {
+            (*a as i64 * *d as i64).cmp(&(*c as i64 * *b as i64))
+        }
+    }
+    impl Integer for i64 {
+        fn to_f64(&self) -> f64 {
+            *self as f64
+        }
+        fn cmp_fraction(a: &Self, b: &Self, c: &Self, d: &Self) -> Ordering {
+            (*a as i128 * *d as i128).cmp(&(*c as i128 * *b as i128))
+        }
+    }
+    impl Integer for i128 {
+        fn to_f64(&self) -> f64 {
+            *self as f64
+        }
+    }
+
+    // (floor(a / b), a - b * floor(a / b)) for b > 0
+    pub fn floor_div_rem<T: Integer>(a: T, b: T) -> (T, T) {
+        let (q, r) = (a.clone() / b.clone(), a % b.clone());
+        if r < T::zero() {
+            (q - T::one(), r + b)
+        } else {
+            (q, r)
+        }
+    }
+
+    pub fn gcd<T: Integer>(x: T, y: T) -> T {
+        let (mut x, mut y) = (abs(x), abs(y));
+        while y != T::zero() {
+            let r = x % y.clone();
+            x = y;
+            y = r;
+        }
+        x
+    }
+
+    fn abs<T: Integer>(x: T) -> T {
+        if x < T::zero() {
+            -x
+        } else {
+            x
+        }
+    }
+
+    pub fn simplify<T: Integer>(x: T, y: T) -> (T, T) {
+        if x == T::zero() {
+            return (T::zero(), T::one());
+        }
+        let g = gcd(x.clone(), y.clone());
+        (x / g.clone(), y / g)
+    }
+
+    #[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+    pub struct Ratio<T = i64> {
+        num: T,
+        den: T,
+    }
+
+    impl<T: Integer> Ratio<T> {
+        pub fn new(num: T, den: T) -> Self {
+            assert!(den != T::zero());
             let (num, den) = simplify(num, den);
-            if den < 0.into() {
+            if den < T::zero() {
                 Ratio {
                     num: -num,
                     den: -den,
@@ -33,59 +114,118 @@ pub mod ratio {
                 Ratio { num, den }
             }
         }
+
+        pub fn numer(&self) -> &T {
+            &self.num
+        }
+
+        pub fn denom(&self) -> &T {
+            &self.den
+        }
+
+        pub fn floor(&self) -> T {
+            floor_div_rem(self.num.clone(), self.den.clone()).0
+        }
+
+        pub fn ceil(&self) -> T {
+            -floor_div_rem(-self.num.clone(), self.den.clone()).0
+        }
+
+        pub fn abs(&self) -> Self {
+            Ratio {
+                num: abs(self.num.clone()),
+                den: self.den.clone(),
+            }
+        }
+
+        pub fn recip(&self) -> Self {
+            assert!(self.num != T::zero());
+            Ratio::new(self.den.clone(), self.num.clone())
+        }
+
+        pub fn to_f64(&self) -> f64 {
+            self.num.to_f64() / self.den.to_f64()
+        }
     }
-    impl Default for Ratio {
+    impl<T: Integer> Default for Ratio<T> {
         fn default() -> Self {
-            Self { num: 0, den: 1 }
+            Ratio {
+                num: T::zero(),
+                den: T::one(),
+            }
+        }
+    }
+    impl<T: Integer> Zero for Ratio<T> {
+        fn zero() -> Self {
+            Ratio::default()
+        }
+        fn is_zero(&self) -> bool {
+            self.num.is_zero()
         }
     }
-    impl Add for Ratio {
-        type Output = Ratio;
+    impl<T: Integer> One for Ratio<T> {
+        fn one() -> Self {
+            Ratio {
+                num: T::one(),
+                den: T::one(),
+            }
+        }
+    }
+    impl<T: Integer + Copy> Field for Ratio<T> {}
+    impl<T: Integer> Add for Ratio<T> {
+        type Output = Ratio<T>;
         fn add(self, rhs: Self) -> Self::Output {
-            Ratio::new(self.num * rhs.den + rhs.num * self.den, self.den * rhs.den)
+            let g = gcd(self.den.clone(), rhs.den.clone());
+            let (b, d) = (self.den / g.clone(), rhs.den.clone() / g);
+            Ratio::new(self.num * d + rhs.num * b.clone(), b * rhs.den)
         }
     }
-    impl AddAssign for Ratio {
+    impl<T: Integer> AddAssign for Ratio<T> {
         fn add_assign(&mut self, rhs: Self) {
-            *self = *self + rhs;
+            *self = self.clone() + rhs;
         }
     }
-    impl Sub for Ratio {
-        type Output = Ratio;
+    impl<T: Integer> Sub for Ratio<T> {
+        type Output = Ratio<T>;
         fn sub(self, rhs: Self) -> Self::Output {
-            Ratio::new(self.num * rhs.den - rhs.num * self.den, self.den * rhs.den)
+            self + -rhs
         }
     }
-    impl SubAssign for Ratio {
+    impl<T: Integer> SubAssign for Ratio<T> {
         fn sub_assign(&mut self, rhs: Self) {
-            *self = *self - rhs;
+            *self = self.clone() - rhs;
         }
     }
-    impl Mul for Ratio {
-        type Output = Ratio;
+    impl<T: Integer> Mul for Ratio<T> {
+        type Output = Ratio<T>;
         fn mul(self, rhs: Self) -> Self::Output {
-            Ratio::new(self.num * rhs.num, self.den * rhs.den)
+            let g1 = gcd(self.num.clone(), rhs.den.clone());
+            let g2 = gcd(rhs.num.clone(), self.den.clone());
+            Ratio::new(
+                self.num / g1.clone() * (rhs.num / g2.clone()),
+                self.den / g2 * (rhs.den / g1),
+            )
         }
     }
-    impl MulAssign for Ratio {
+    impl<T: Integer> MulAssign for Ratio<T> {
         fn mul_assign(&mut self, rhs: Self) {
-            *self = *self * rhs;
+            *self = self.clone() * rhs;
         }
     }
-    impl Div for Ratio {
-        type Output = Ratio;
+    impl<T: Integer> Div for Ratio<T> {
+        type Output = Ratio<T>;
+        #[allow(clippy::suspicious_arithmetic_impl)]
         fn div(self, rhs: Self) -> Self::Output {
-            assert!(rhs.num != 0.into());
-            Ratio::new(self.num * rhs.den, self.den * rhs.num)
+            self * rhs.recip()
         }
     }
-    impl DivAssign for Ratio {
+    impl<T: Integer> DivAssign for Ratio<T> {
         fn div_assign(&mut self, rhs: Self) {
-            *self = *self / rhs;
+            *self = self.clone() / rhs;
         }
     }
-    impl Neg for Ratio {
-        type Output = Ratio;
+    impl<T: Integer> Neg for Ratio<T> {
+        type Output = Ratio<T>;
         fn neg(self) -> Self::Output {
             Self::Output {
                 num: -self.num,
@@ -93,21 +233,63 @@ pub mod ratio {
             }
         }
     }
-    impl<T: Into<i64>> From<T> for Ratio {
+    impl<T: Integer> From<T> for Ratio<T> {
         fn from(value: T) -> Self {
-            Self::new(value.into(), 1)
+            Ratio {
+                num: value,
+                den: T::one(),
+            }
+        }
+    }
+    // the widening conversions the i64-only Ratio accepted
+    macro_rules! from_narrow {
+        ($($t:ty),*) => {$(
+            impl From<$t> for Ratio<i64> {
+                fn from(value: $t) -> Self {
+                    Ratio::from(value as i64)
+                }
+            }
+        )*};
+    }
+    from_narrow!(i8, i16, i32, u8, u16, u32);
+    impl<T: Integer> Ord for Ratio<T> {
+        fn cmp(&self, other: &Self) -> Ordering {
+            T::cmp_fraction(&self.num, &self.den, &other.num, &other.den)
         }
     }
-    impl PartialOrd for Ratio {
-        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-            (self.num * other.den).partial_cmp(&(other.num * self.den))
+    impl<T: Integer> PartialOrd for Ratio<T> {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
         }
     }
-    impl std::fmt::Display for Ratio {
+    impl<T: std::fmt::Display> std::fmt::Display for Ratio<T> {
         fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
             write!(f, "{}/{}", self.num, self.den)
         }
     }
+
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct ParseRatioError;
+
+    impl std::fmt::Display for ParseRatioError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "invalid fraction, expected \"a/b\" or \"a\"")
+        }
+    }
+
+    // "a/b" or "a"
+    impl<T: Integer + FromStr> FromStr for Ratio<T> {
+        type Err = ParseRatioError;
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let (num, den) = s.split_once('/').unwrap_or((s, "1"));
+            let num: T = num.trim().parse().map_err(|_| ParseRatioError)?;
+            let den: T = den.trim().parse().map_err(|_| ParseRatioError)?;
+            if den == T::zero() {
+                return Err(ParseRatioError);
+            }
+            Ok(Ratio::new(num, den))
+        }
+    }
 }
 
 #[cfg(test)]
@@ -125,5 +307,49 @@ mod tests {
         assert_eq!(x / 2.into(), Ratio64::new(3, 8));
         assert_eq!(-x, Ratio64::new(-3, 4));
         assert!(x < y);
+        assert_eq!(Ratio64::from(2_i32), Ratio64::new(2, 1));
+        let z: Ratio64 = 7_u32.into();
+        assert_eq!(x + z, Ratio64::new(31, 4));
+    }
+
+    #[test]
+    fn test_ratio_generic() {
+        use super::ratio::Ratio;
+        use crate::math::bigint::bigint::BigInt;
+
+        let big = i64::MAX / 3;
+        let x = Ratio::<i64>::new(big, big - 1);
+        let y = Ratio::<i64>::new(big - 1, big - 2);
+        assert!(x < y && -y < -x);
+
+        let m = i128::MAX;
+        let x = Ratio::<i128>::new(m - 2, m - 1);
+        let y = Ratio::<i128>::new(m - 1, m);
+        assert!(x < y);
+        assert_eq!(x.cmp(&x), std::cmp::Ordering::Equal);
+        assert!(Ratio::<i128>::new(-(m - 1), m) < Ratio::new(-(m - 2), m - 1));
+
+        let r = Ratio::<i64>::new(-7, 2);
+        assert_eq!((r.floor(), r.ceil()), (-4, -3));
+        assert_eq!((r.abs().floor(), r.abs().ceil()), (3, 4));
+        assert_eq!(Ratio::<i64>::new(6, 3).ceil(), 2);
+        assert_eq!(r.recip(), Ratio::new(-2, 7));
+        assert_eq!(r.to_f64(), -3.5);
+
+        assert_eq!("-6/4".parse(), Ok(Ratio::<i64>::new(-3, 2)));
+        assert_eq!(" 5 ".parse(), Ok(Ratio::<i32>::new(5, 1)));
+        assert!("1/0".parse::<Ratio>().is_err());
+        assert!("1/x".parse::<Ratio>().is_err());
+
+        let x: Ratio<BigInt> = "123456789012345678901234567890/987654321098765432109876543210"
+            .parse()
+            .unwrap();
+        let sum = (1..=30).fold(Ratio::<BigInt>::default(), |acc, i| {
+            acc + Ratio::new(BigInt::from(1), BigInt::from(i))
+        });
+        assert_eq!(sum.to_string(), "9304682830147/2329089562800");
+        assert_eq!(x.clone() * x.recip(), Ratio::from(BigInt::from(1)));
+        assert!(x < Ratio::new(BigInt::from(1), BigInt::from(8)));
+        assert_eq!(x.floor(), BigInt::from(0));
     }
 }
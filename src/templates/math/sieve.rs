@@ -0,0 +1,154 @@
+pub mod sieve {
+    #[derive(Clone, Debug)]
+    pub struct LinearSieve {
+        n: usize,
+        primes: Vec<usize>,
+        spf: Vec<usize>,
+        // largest power of spf[i] dividing i, and its exponent
+        pk: Vec<usize>,
+        exp: Vec<u32>,
+    }
+
+    impl LinearSieve {
+        pub fn new(n: usize) -> Self {
+            let mut primes = vec![];
+            let mut spf = vec![0; n + 1];
+            let mut pk = vec![0; n + 1];
+            let mut exp = vec![0; n + 1];
+            if n >= 1 {
+                pk[1] = 1;
+            }
+            for i in 2..=n {
+                if spf[i] == 0 {
+                    spf[i] = i;
+                    pk[i] = i;
+                    exp[i] = 1;
+                    primes.push(i);
+                }
+                for &p in primes.iter() {
+                    if p > spf[i] || i * p > n {
+                        break;
+                    }
+                    let j = i * p;
+                    spf[j] = p;
+                    if p == spf[i] {
+                        pk[j] = pk[i] * p;
+                        exp[j] = exp[i] + 1;
+                    } else {
+                        pk[j] = p;
+                        exp[j] = 1;
+                    }
+                }
+            }
+            LinearSieve {
+                n,
+                primes,
+                spf,
+                pk,
+                exp,
+            }
+        }
+
+        pub fn primes(&self) -> &[usize] {
+            &self.primes
+        }
+
+        pub fn spf(&self) -> &[usize] {
+            &self.spf
+        }
+
+        pub fn is_prime(&self, x: usize) -> bool {
+            x >= 2 && self.spf[x] == x
+        }
+
+        pub fn factorize(&self, mut x: usize) -> Vec<(usize, u32)> {
+            assert!(x >= 1 && x <= self.n);
+            let mut result = vec![];
+            while x > 1 {
+                result.push((self.spf[x], self.exp[x]));
+                x /= self.pk[x];
+            }
+            result
+        }
+
+        // table of the multiplicative function with f(p, e) at p^e, index 0 is T::default()
+        pub fn multiplicative<T, F>(&self, one: T, f: F) -> Vec<T>
+        where
+            T: Clone + Default + std::ops::Mul<Output = T>,
+            F: Fn(usize, u32) -> T,
+        {
+            let mut g = vec![T::default(); self.n + 1];
+            if self.n >= 1 {
+                g[1] = one;
+            }
+            for i in 2..=self.n {
+                g[i] = if self.pk[i] == i {
+                    f(self.spf[i], self.exp[i])
+                } else {
+                    g[i / self.pk[i]].clone() * g[self.pk[i]].clone()
+                };
+            }
+            g
+        }
+
+        pub fn euler_phi(&self) -> Vec<u64> {
+            self.multiplicative(1, |p, e| (p as u64 - 1) * (p as u64).pow(e - 1))
+        }
+
+        pub fn mobius(&self) -> Vec<i64> {
+            self.multiplicative(1, |_, e| if e == 1 { -1 } else { 0 })
+        }
+
+        pub fn divisor_count(&self) -> Vec<u64> {
+            self.multiplicative(1, |_, e| e as u64 + 1)
+        }
+
+        pub fn divisor_sum(&self) -> Vec<u64> {
+            self.multiplicative(1, |p, e| ((p as u64).pow(e + 1) - 1) / (p as u64 - 1))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::sieve::*;
+
+    #[test]
+    fn test_linear_sieve() {
+        let n = 1000;
+        let sieve = LinearSieve::new(n);
+        assert_eq!(&sieve.primes()[..10], &[2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+        assert_eq!(sieve.primes().len(), 168);
+        assert_eq!(sieve.factorize(360), vec![(2, 3), (3, 2), (5, 1)]);
+        assert!(sieve.is_prime(997) && !sieve.is_prime(999) && !sieve.is_prime(1));
+
+        let phi = sieve.euler_phi();
+        let mu = sieve.mobius();
+        let d = sieve.divisor_count();
+        let sigma = sieve.divisor_sum();
+        let gcd = |mut a: usize, mut b: usize| {
+            while b != 0 {
+                (a, b) = (b, a % b);
+            }
+            a
+        };
+        for i in 2..=n {
+            assert_eq!(sieve.spf()[i], (2..=i).find(|p| i % p == 0).unwrap());
+        }
+        for i in 1..=n {
+            assert_eq!(phi[i], (1..=i).filter(|&k| gcd(i, k) == 1).count() as u64);
+            let divs: Vec<usize> = (1..=i).filter(|k| i % k == 0).collect();
+            assert_eq!(d[i], divs.len() as u64);
+            assert_eq!(sigma[i], divs.iter().sum::<usize>() as u64);
+            assert_eq!(divs.iter().map(|&k| mu[k]).sum::<i64>(), (i == 1) as i64);
+        }
+
+        use crate::math::modint::modint::mint998244353;
+        let square: Vec<mint998244353> = sieve.multiplicative(1.into(), |p, e| {
+            mint998244353::new(p as i64).pow(2 * e as u64)
+        });
+        for (i, &x) in square.iter().enumerate() {
+            assert_eq!(x, mint998244353::new((i * i) as i64));
+        }
+    }
+}
@@ -0,0 +1,289 @@
+pub mod stern_brocot {
+    use crate::math::ratio::ratio::{floor_div_rem, Integer, Ratio};
+
+    // [a0; a1, ..., ak] with ak > 1 unless k = 0
+    pub fn continued_fraction<T: Integer>(r: &Ratio<T>) -> Vec<T> {
+        let (mut n, mut d) = (r.numer().clone(), r.denom().clone());
+        let mut a = vec![];
+        while d != T::zero() {
+            let (q, rem) = floor_div_rem(n, d.clone());
+            a.push(q);
+            (n, d) = (d, rem);
+        }
+        a
+    }
+
+    pub fn from_continued_fraction<T: Integer>(a: &[T]) -> Ratio<T> {
+        convergents_of(a).pop().expect("empty continued fraction")
+    }
+
+    fn convergents_of<T: Integer>(a: &[T]) -> Vec<Ratio<T>> {
+        let (mut p0, mut q0, mut p1, mut q1) = (T::zero(), T::one(), T::one(), T::zero());
+        a.iter()
+            .map(|x| {
+                let (p, q) = (
+                    x.clone() * p1.clone() + p0.clone(),
+                    x.clone() * q1.clone() + q0.clone(),
+                );
+                (p0, q0, p1, q1) = (p1.clone(), q1.clone(), p.clone(), q.clone());
+                Ratio::new(p, q)
+            })
+            .collect()
+    }
+
+    pub fn convergents<T: Integer>(r: &Ratio<T>) -> Vec<Ratio<T>> {
+        convergents_of(&continued_fraction(r))
+    }
+
+    // closest fraction to r with denominator at most max_den
+    pub fn best_approximation<T: Integer>(r: &Ratio<T>, max_den: T) -> Ratio<T> {
+        assert!(max_den >= T::one());
+        if *r.denom() <= max_den {
+            return r.clone();
+        }
+        let (mut p0, mut q0, mut p1, mut q1) = (T::zero(), T::one(), T::one(), T::zero());
+        let (mut n, mut d) = (r.numer().clone(), r.denom().clone());
+        let (a, rem) = loop {
+            let (a, rem) = floor_div_rem(n, d.clone());
+            let q2 = q0.clone() + a.clone() * q1.clone();
+            if q2 > max_den {
+                break (a, rem);
+            }
+            (p0, q0, p1, q1) = (p1.clone(), q1.clone(), p0 + a * p1, q2);
+            (n, d) = (d, rem);
+        };
+        // last convergent p1 / q1 against the best semiconvergent on the other side,
+        // with x = a + rem / d the complete quotient the distances are 1 / (q1 (q1 x + q0))
+        // and (x - k) / ((q0 + k q1) (q1 x + q0)), so the convergent wins iff
+        // (a - 2k) + rem / d >= q0 / q1, where both fractions lie in [0, 1]
+        let k = (max_den - q0.clone()) / q1.clone();
+        let w = a - k.clone() - k.clone();
+        let convergent_wins = if w == T::zero() {
+            T::cmp_fraction(&rem, &d, &q0, &q1) != std::cmp::Ordering::Less
+        } else {
+            w > T::zero()
+        };
+        if convergent_wins {
+            Ratio::new(p1, q1)
+        } else {
+            Ratio::new(p0 + k.clone() * p1, q0 + k * q1)
+        }
+    }
+
+    // run-length moves from 1/1 to r > 0, 'L' towards 0 and 'R' towards infinity
+    pub fn path<T: Integer>(r: &Ratio<T>) -> Vec<(char, T)> {
+        assert!(*r.numer() > T::zero());
+        let mut a = continued_fraction(r);
+        let last = a.pop().unwrap() - T::one();
+        a.push(last);
+        a.into_iter()
+            .enumerate()
+            .filter(|(_, k)| *k != T::zero())
+            .map(|(i, k)| (if i & 1 == 0 { 'R' } else { 'L' }, k))
+            .collect()
+    }
+
+    pub fn from_path<T: Integer>(path: &[(char, T)]) -> Ratio<T> {
+        // the current node is the mediant of the bounds lp / lq and rp / rq
+        let (mut lp, mut lq, mut rp, mut rq) = (T::zero(), T::one(), T::one(), T::zero());
+        for (c, k) in path {
+            match c {
+                'R' => (lp, lq) = (lp + k.clone() * rp.clone(), lq + k.clone() * rq.clone()),
+                'L' => (rp, rq) = (rp + k.clone() * lp.clone(), rq + k.clone() * lq.clone()),
+                _ => panic!("invalid move {}", c),
+            }
+        }
+        Ratio::new(lp + rp, lq + rq)
+    }
+
+    pub fn lca<T: Integer>(a: &Ratio<T>, b: &Ratio<T>) -> Ratio<T> {
+        let (pa, pb) = (path(a), path(b));
+        let mut common = vec![];
+        for ((c1, k1), (c2, k2)) in pa.into_iter().zip(pb) {
+            if c1 != c2 {
+                break;
+            }
+            let same = k1 == k2;
+            common.push((c1, k1.min(k2)));
+            if !same {
+                break;
+            }
+        }
+        from_path(&common)
+    }
+
+    // p / q with a / b < p / q < c / d, minimal q, where b > 0 and d = 0 means infinity
+    fn between<T: Integer>(a: T, b: T, c: T, d: T) -> (T, T) {
+        let (t, r) = floor_div_rem(a, b.clone());
+        let next = t.clone() + T::one();
+        if d == T::zero() || next.clone() * d.clone() < c {
+            return (next, T::one());
+        }
+        // x = t + 1 / y with d / (c - t * d) < y < b / r
+        let (p, q) = between(d.clone(), c - t.clone() * d, b, r);
+        (t * p.clone() + q, p)
+    }
+
+    // the fraction with minimal denominator strictly between lo and hi
+    pub fn simplest_between<T: Integer>(lo: &Ratio<T>, hi: &Ratio<T>) -> Ratio<T> {
+        assert!(lo < hi);
+        let (p, q) = between(
+            lo.numer().clone(),
+            lo.denom().clone(),
+            hi.numer().clone(),
+            hi.denom().clone(),
+        );
+        Ratio::new(p, q)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::stern_brocot::*;
+    use crate::math::ratio::ratio::Ratio;
+    use crate::test_util::test_util::XorShift;
+
+    fn random_ratio(rng: &mut XorShift) -> Ratio {
+        let num = rng.range(-200, 200);
+        let den = rng.range(1, 60);
+        Ratio::new(num, den)
+    }
+
+    #[test]
+    fn test_continued_fraction() {
+        let r = Ratio::<i64>::new(415, 93);
+        assert_eq!(continued_fraction(&r), vec![4, 2, 6, 7]);
+        assert_eq!(
+            convergents(&r),
+            vec![
+                Ratio::new(4, 1),
+                Ratio::new(9, 2),
+                Ratio::new(58, 13),
+                Ratio::new(415, 93)
+            ]
+        );
+        assert_eq!(
+            continued_fraction(&Ratio::<i64>::new(-7, 3)),
+            vec![-3, 1, 2]
+        );
+
+        let mut rng = XorShift::default();
+        for _ in 0..500 {
+            let r = random_ratio(&mut rng);
+            assert_eq!(from_continued_fraction(&continued_fraction(&r)), r);
+        }
+    }
+
+    #[test]
+    fn test_best_approximation() {
+        let pi = Ratio::<i64>::new(3141592653589793, 1000000000000000);
+        assert_eq!(best_approximation(&pi, 10), Ratio::new(22, 7));
+        assert_eq!(best_approximation(&pi, 1000), Ratio::new(355, 113));
+        // nothing may be built over the full 1e17 denominator
+        let pi = Ratio::<i64>::new(314159265358979323, 100000000000000000);
+        assert_eq!(
+            best_approximation(&pi, 1_000_000),
+            Ratio::new(3126535, 995207)
+        );
+        assert_eq!(
+            best_approximation(&pi, 1_000_000_000),
+            Ratio::new(1726375805, 549522486)
+        );
+        // max_den near i64::MAX, where 2k q1 would overflow
+        let m = i64::MAX;
+        let cases = [
+            ((1, m), m - 1, (1, m - 1)),
+            ((m - 1, m), m - 1, (m - 2, m - 1)),
+            (
+                (m / 2, m),
+                m / 2 + 7,
+                (2305843009213693954, 4611686018427387909),
+            ),
+            ((3, m), m / 2, (1, 3074457345618258602)),
+        ];
+        for ((p, q), max_den, (ep, eq)) in cases {
+            let found = best_approximation(&Ratio::<i64>::new(p, q), max_den);
+            assert_eq!(found, Ratio::new(ep, eq));
+        }
+        let r = Ratio::<i64>::new(-999999999999999989, 1000000000000000000);
+        assert_eq!(best_approximation(&r, 1_000_000_000_000), Ratio::from(-1));
+        assert_eq!(
+            best_approximation(&r, 99999999999999999),
+            Ratio::new(-90909090909090908, 90909090909090909)
+        );
+
+        let mut rng = XorShift::default();
+        for _ in 0..500 {
+            let r = random_ratio(&mut rng);
+            let max_den = rng.range(1, 20);
+            let dist = |x: &Ratio| (*x - r).abs();
+            let best = (1..=max_den)
+                .flat_map(|q| {
+                    let p = (r * Ratio::from(q)).floor();
+                    [Ratio::new(p, q), Ratio::new(p + 1, q)]
+                })
+                .min_by_key(dist)
+                .unwrap();
+            let found = best_approximation(&r, max_den);
+            assert!(*found.denom() <= max_den);
+            assert_eq!(dist(&found), dist(&best));
+        }
+    }
+
+    #[test]
+    fn test_stern_brocot_tree() {
+        assert!(path(&Ratio::<i64>::new(1, 1)).is_empty());
+        assert_eq!(path(&Ratio::<i64>::new(2, 5)), vec![('L', 2), ('R', 1)]);
+        assert_eq!(
+            lca(&Ratio::<i64>::new(2, 5), &Ratio::new(1, 4)),
+            Ratio::new(1, 3)
+        );
+        assert_eq!(
+            lca(&Ratio::<i64>::new(2, 5), &Ratio::new(1, 3)),
+            Ratio::new(1, 3)
+        );
+
+        let mut rng = XorShift::default();
+        for _ in 0..500 {
+            let a = random_ratio(&mut rng).abs() + Ratio::new(1, 61);
+            let b = random_ratio(&mut rng).abs() + Ratio::new(1, 61);
+            assert_eq!(from_path(&path(&a)), a);
+
+            // the lca is the simplest fraction in the closed range spanned by a and b
+            let (lo, hi) = (a.min(b), a.max(b));
+            let l = lca(&a, &b);
+            assert!(lo <= l && l <= hi);
+            for q in 1..*l.denom() {
+                let p = (lo * Ratio::from(q)).ceil();
+                assert!(Ratio::new(p, q) > hi);
+            }
+        }
+    }
+
+    #[test]
+    fn test_simplest_between() {
+        assert_eq!(
+            simplest_between(&Ratio::<i64>::new(1, 3), &Ratio::new(1, 2)),
+            Ratio::new(2, 5)
+        );
+        assert_eq!(
+            simplest_between(&Ratio::<i64>::new(-1, 2), &Ratio::new(1, 3)),
+            Ratio::new(0, 1)
+        );
+        let mut rng = XorShift::default();
+        for _ in 0..500 {
+            let a = random_ratio(&mut rng);
+            let b = random_ratio(&mut rng);
+            if a == b {
+                continue;
+            }
+            let (lo, hi) = (a.min(b), a.max(b));
+            let s = simplest_between(&lo, &hi);
+            assert!(lo < s && s < hi);
+            let q = (1..)
+                .find(|&q| Ratio::new((lo * Ratio::from(q)).floor() + 1, q) < hi)
+                .unwrap();
+            assert_eq!(*s.denom(), q);
+        }
+    }
+}
@@ -1,7 +1,31 @@
+#![allow(clippy::module_inception)]
+// templates are pasted into judges with older toolchains, is_multiple_of needs 1.87
+#![allow(clippy::manual_is_multiple_of)]
+
 pub mod math {
+    pub mod algebra;
+    pub mod bigint;
+    pub mod binomial_mod;
+    pub mod bitwise_transform;
+    pub mod combinatorics;
+    pub mod convolution;
+    pub mod dyn_modint;
+    pub mod floor_sum;
+    pub mod formal_power_series;
+    pub mod gaussian_elimination;
+    pub mod gf2;
+    pub mod interpolation;
+    pub mod linear_recurrence;
+    pub mod matrix;
     pub mod modint;
+    pub mod montgomery;
+    pub mod number_theory;
+    pub mod prime;
+    pub mod prime_count;
     pub mod qpow;
     pub mod ratio;
+    pub mod sieve;
+    pub mod stern_brocot;
 }
 
 pub mod graph {
@@ -10,5 +34,9 @@ pub mod graph {
 
 pub mod data_structure {
     pub mod fenwick;
+    pub mod lazy_segment_tree;
     pub mod segment_tree;
 }
+
+#[cfg(test)]
+pub mod test_util;
@@ -0,0 +1,34 @@
+pub mod test_util {
+    // xorshift64, shared by the randomized tests
+    pub struct XorShift(u64);
+
+    impl XorShift {
+        pub fn new(seed: u64) -> Self {
+            assert!(seed != 0);
+            XorShift(seed)
+        }
+
+        pub fn next_u64(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        // uniform-ish in 0..n
+        pub fn below(&mut self, n: u64) -> u64 {
+            self.next_u64() % n
+        }
+
+        // uniform-ish in lo..=hi
+        pub fn range(&mut self, lo: i64, hi: i64) -> i64 {
+            lo + (self.next_u64() % (hi - lo + 1) as u64) as i64
+        }
+    }
+
+    impl Default for XorShift {
+        fn default() -> Self {
+            XorShift::new(88172645463325252)
+        }
+    }
+}